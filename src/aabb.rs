@@ -56,6 +56,37 @@ impl Aabb {
         Self { x, y, z }
     }
 
+    pub fn overlap(box0: &Aabb, box1: &Aabb) -> Aabb {
+        // The box shared by both, empty along any axis where they don't meet
+        let overlap = |a: &Interval, b: &Interval| {
+            let (min, max) = (a.min.max(b.min), a.max.min(b.max));
+            if min <= max {
+                Interval::new(min, max)
+            } else {
+                Interval::EMPTY
+            }
+        };
+
+        Self {
+            x: overlap(&box0.x, &box1.x),
+            y: overlap(&box0.y, &box1.y),
+            z: overlap(&box0.z, &box1.z),
+        }
+    }
+
+    pub fn from_circle(center: &Point3, normal: &Vec3, radius: f32) -> Aabb {
+        // Tightest box around a circle facing along the normal, which reaches out less along the
+        // axes closer to the normal
+        let n = normal.unit_vector();
+        let extent = Vec3::new(
+            radius * (1.0 - n.x() * n.x()).max(0.0).sqrt(),
+            radius * (1.0 - n.y() * n.y()).max(0.0).sqrt(),
+            radius * (1.0 - n.z() * n.z()).max(0.0).sqrt(),
+        );
+
+        Self::from_points(&(*center - extent), &(*center + extent))
+    }
+
     pub fn axis_interval(&self, n: usize) -> &Interval {
         match n {
             1 => &self.y,
@@ -64,8 +95,12 @@ impl Aabb {
         }
     }
 
-    pub fn hit(&self, ray: &Ray, mut ray_t: Interval) -> bool {
-        //println!("Ray Interval: {:?}, Ray: {:?}", ray_t, ray);
+    pub fn hit(&self, ray: &Ray, ray_t: Interval) -> bool {
+        self.clip(ray, ray_t).is_some()
+    }
+
+    pub fn clip(&self, ray: &Ray, mut ray_t: Interval) -> Option<Interval> {
+        // The part of the ray interval inside the box, if there is any
         let ray_origin = ray.origin();
         let ray_dir = ray.direction();
 
@@ -85,11 +120,19 @@ impl Aabb {
             }
 
             if ray_t.max <= ray_t.min {
-                return false;
+                return None;
             }
         }
 
-        true
+        Some(ray_t)
+    }
+
+    pub fn is_bounded(&self) -> bool {
+        // False for boxes around objects that reach out forever, like infinite planes, an empty
+        // axis holds nothing so it doesn't reach anywhere
+        [self.x, self.y, self.z]
+            .iter()
+            .all(|axis| axis.min > axis.max || axis.size().is_finite())
     }
 
     pub fn longest_axis(&self) -> usize {
@@ -102,6 +145,15 @@ impl Aabb {
         }
     }
 
+    pub fn centroid(&self) -> Point3 {
+        // Returns the point at the center of the bounding box
+        Point3::new(
+            0.5 * (self.x.min + self.x.max),
+            0.5 * (self.y.min + self.y.max),
+            0.5 * (self.z.min + self.z.max),
+        )
+    }
+
     fn pad_to_minimums(&mut self) {
         // Adjust the AABB so that no side is narrower than some delta, padding if necessary
         let delta = 0.001;
@@ -175,4 +227,22 @@ mod test {
         assert!(aabb.z.min == -10.0);
         assert!(aabb.z.max == 7.0);
     }
+
+    #[test]
+    fn bounded_aabb() {
+        assert!(Aabb::EMPTY.is_bounded());
+        assert!(!Aabb::UNIVERSE.is_bounded());
+        assert!(Aabb::new(
+            Interval::new(-3.0, 5.0),
+            Interval::new(-1.0, 50.0),
+            Interval::new(-10.0, 7.0),
+        )
+        .is_bounded());
+        assert!(!Aabb::new(
+            Interval::new(-3.0, 5.0),
+            Interval::UNIVERSE,
+            Interval::new(-10.0, 7.0),
+        )
+        .is_bounded());
+    }
 }
@@ -0,0 +1,104 @@
+use crate::vec3::Vec3;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Interpolation {
+    // Holds each keyframe's value until the next keyframe
+    Step,
+    // Moves at a constant rate between keyframes
+    #[default]
+    Linear,
+    // Eases in and out of every keyframe
+    Smooth,
+}
+
+impl Interpolation {
+    fn weight(&self, f: f32) -> f32 {
+        // How far along the way to the next keyframe, for `f` of the time between them
+        match self {
+            Interpolation::Step => 0.0,
+            Interpolation::Linear => f,
+            Interpolation::Smooth => f * f * (3.0 - 2.0 * f),
+        }
+    }
+}
+
+pub trait Lerp: Copy {
+    fn lerp(&self, other: &Self, f: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, f: f32) -> Self {
+        (1.0 - f) * self + f * other
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(&self, other: &Self, f: f32) -> Self {
+        (1.0 - f) * *self + f * *other
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Track<T> {
+    // Values at increasing times
+    keys: Vec<(f32, T)>,
+    interpolation: Interpolation,
+}
+
+impl<T: Lerp> Track<T> {
+    pub fn new(mut keys: Vec<(f32, T)>, interpolation: Interpolation) -> Self {
+        assert!(!keys.is_empty(), "a track needs at least one keyframe");
+        keys.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Self {
+            keys,
+            interpolation,
+        }
+    }
+
+    pub fn keys(&self) -> &[(f32, T)] {
+        &self.keys
+    }
+
+    pub fn at(&self, time: f32) -> T {
+        // The value holds still before the first keyframe and after the last
+        let next = self.keys.partition_point(|(t, _)| *t <= time);
+        if next == 0 {
+            return self.keys[0].1;
+        }
+        if next == self.keys.len() {
+            return self.keys[next - 1].1;
+        }
+
+        let (t0, v0) = &self.keys[next - 1];
+        let (t1, v1) = &self.keys[next];
+        let f = (time - t0) / (t1 - t0);
+
+        v0.lerp(v1, self.interpolation.weight(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolates_between_keyframes() {
+        let keys = vec![(1.0, 10.0), (0.0, 0.0), (2.0, 10.0)];
+
+        let linear = Track::new(keys.clone(), Interpolation::Linear);
+        assert_eq!(linear.at(-1.0), 0.0);
+        assert_eq!(linear.at(0.25), 2.5);
+        assert_eq!(linear.at(1.5), 10.0);
+        assert_eq!(linear.at(3.0), 10.0);
+
+        let step = Track::new(keys.clone(), Interpolation::Step);
+        assert_eq!(step.at(0.9), 0.0);
+        assert_eq!(step.at(1.0), 10.0);
+
+        let smooth = Track::new(keys, Interpolation::Smooth);
+        assert!(smooth.at(0.25) < 2.5);
+        assert_eq!(smooth.at(0.5), 5.0);
+        assert!(smooth.at(0.75) > 7.5);
+    }
+}
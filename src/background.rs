@@ -0,0 +1,107 @@
+use std::{f32::consts::PI, sync::Arc};
+
+use ::image::DynamicImage;
+
+use crate::{color::Color, light::LightSample, texture::TextureType, vec3::Vec3};
+
+pub mod environment;
+pub mod gradient;
+pub mod sky;
+
+use environment::Environment;
+use gradient::Gradient;
+use sky::Sky;
+
+pub trait Background {
+    fn value(&self, _direction: &Vec3) -> Color {
+        Color::default()
+    }
+
+    fn pdf_value(&self, _direction: &Vec3) -> f32 {
+        // Density `sample` picks the direction with, 0 for backgrounds that aren't sampled
+        0.0
+    }
+
+    fn sample(&self) -> Option<(LightSample, f32)> {
+        None
+    }
+}
+
+#[derive(Clone)]
+pub enum BackgroundType {
+    Constant(Color),
+    Gradient(Gradient),
+    Environment(Environment),
+    Sky(Sky),
+    Texture(Arc<TextureType>),
+}
+
+impl From<Color> for BackgroundType {
+    fn from(value: Color) -> Self {
+        BackgroundType::Constant(value)
+    }
+}
+
+impl From<&Color> for BackgroundType {
+    fn from(value: &Color) -> Self {
+        BackgroundType::Constant(*value)
+    }
+}
+
+impl From<TextureType> for BackgroundType {
+    fn from(value: TextureType) -> Self {
+        BackgroundType::Texture(Arc::new(value))
+    }
+}
+
+impl BackgroundType {
+    pub fn gradient(bottom: Color, top: Color) -> BackgroundType {
+        BackgroundType::Gradient(Gradient::new(bottom, top))
+    }
+
+    pub fn environment(image: DynamicImage, rotation: f32, intensity: f32) -> BackgroundType {
+        BackgroundType::Environment(Environment::new(image, rotation, intensity))
+    }
+
+    pub fn value(&self, direction: &Vec3) -> Color {
+        match self {
+            BackgroundType::Constant(color) => *color,
+            BackgroundType::Gradient(gradient) => gradient.value(direction),
+            BackgroundType::Environment(env) => env.value(direction),
+            BackgroundType::Sky(sky) => sky.value(direction),
+            BackgroundType::Texture(tex) => {
+                // Wrap the texture around the scene with image-style coordinates, v going up
+                let (u, v) = equirectangular_uv(direction);
+                tex.value(u, 1.0 - v, &direction.unit_vector())
+            }
+        }
+    }
+
+    pub fn pdf_value(&self, direction: &Vec3) -> f32 {
+        match self {
+            BackgroundType::Gradient(gradient) => gradient.pdf_value(direction),
+            BackgroundType::Environment(env) => env.pdf_value(direction),
+            BackgroundType::Sky(sky) => sky.pdf_value(direction),
+            BackgroundType::Constant(_) | BackgroundType::Texture(_) => 0.0,
+        }
+    }
+
+    pub fn sample(&self) -> Option<(LightSample, f32)> {
+        match self {
+            BackgroundType::Gradient(gradient) => gradient.sample(),
+            BackgroundType::Environment(env) => env.sample(),
+            BackgroundType::Sky(sky) => sky.sample(),
+            BackgroundType::Constant(_) | BackgroundType::Texture(_) => None,
+        }
+    }
+}
+
+pub fn equirectangular_uv(direction: &Vec3) -> (f32, f32) {
+    // Longitude and latitude of a direction mapped to [0, 1], u = 0.5 looking down -Z and v = 0
+    // straight up
+    let d = direction.unit_vector();
+    let phi = d.x().atan2(-d.z());
+    let theta = d.y().clamp(-1.0, 1.0).acos();
+
+    ((phi + PI) / (2.0 * PI), theta / PI)
+}
@@ -0,0 +1,121 @@
+use std::{f32::consts::PI, sync::Arc};
+
+use image::DynamicImage;
+
+use crate::{
+    color::Color, distribution::Distribution2D, light::LightSample, utility::degree_to_radians,
+    vec3::Vec3,
+};
+
+use super::{equirectangular_uv, Background};
+
+#[derive(Clone)]
+pub struct Environment {
+    width: usize,
+    height: usize,
+    // Linear radiance of every texel of the equirectangular image, row by row from the top
+    radiance: Arc<Vec<Color>>,
+    // Scale applied to the radiance of the whole map
+    intensity: f32,
+    // Rotation of the map around the vertical axis
+    sin_rotation: f32,
+    cos_rotation: f32,
+    // Distribution of texels proportional to the light they contribute
+    distribution: Arc<Distribution2D>,
+}
+
+impl Environment {
+    pub fn new(image: DynamicImage, rotation: f32, intensity: f32) -> Environment {
+        // `image` is a latitude-longitude map whose centre looks down -Z, usually a Radiance .hdr
+        // or OpenEXR file so the sun keeps its full brightness. `rotation` is in degrees.
+        let image = image.to_rgb32f();
+        let (width, height) = (image.width() as usize, image.height() as usize);
+
+        let radiance: Vec<Color> = image
+            .pixels()
+            .map(|pixel| Color::new(pixel[0], pixel[1], pixel[2]))
+            .collect();
+
+        // Texels near the poles cover less of the sphere, so they're picked less often.
+        let weights: Vec<f32> = radiance
+            .iter()
+            .enumerate()
+            .map(|(index, texel)| {
+                let theta = PI * ((index / width) as f32 + 0.5) / height as f32;
+                texel.luminance() * theta.sin()
+            })
+            .collect();
+
+        let radians = degree_to_radians(rotation);
+
+        Environment {
+            width,
+            height,
+            distribution: Arc::new(Distribution2D::new(&weights, width, height)),
+            radiance: Arc::new(radiance),
+            intensity,
+            sin_rotation: radians.sin(),
+            cos_rotation: radians.cos(),
+        }
+    }
+
+    fn direction_to_uv(&self, direction: &Vec3) -> (f32, f32) {
+        // Undo the rotation of the map before finding where the direction lands on it
+        let x = self.cos_rotation * direction.x() - self.sin_rotation * direction.z();
+        let z = self.sin_rotation * direction.x() + self.cos_rotation * direction.z();
+
+        equirectangular_uv(&Vec3::new(x, direction.y(), z))
+    }
+
+    fn uv_to_direction(&self, u: f32, v: f32) -> Vec3 {
+        let phi = 2.0 * PI * u - PI;
+        let theta = PI * v;
+        let (x, z) = (theta.sin() * phi.sin(), -theta.sin() * phi.cos());
+
+        Vec3::new(
+            self.cos_rotation * x + self.sin_rotation * z,
+            theta.cos(),
+            -self.sin_rotation * x + self.cos_rotation * z,
+        )
+    }
+
+    fn lookup(&self, u: f32, v: f32) -> Color {
+        let i = ((u * self.width as f32) as usize).min(self.width - 1);
+        let j = ((v * self.height as f32) as usize).min(self.height - 1);
+        self.intensity * self.radiance[j * self.width + i]
+    }
+}
+
+impl Background for Environment {
+    fn value(&self, direction: &Vec3) -> Color {
+        let (u, v) = self.direction_to_uv(direction);
+        self.lookup(u, v)
+    }
+
+    fn pdf_value(&self, direction: &Vec3) -> f32 {
+        let (u, v) = self.direction_to_uv(direction);
+        let sin_theta = (PI * v).sin();
+        if sin_theta <= 0.0 {
+            return 0.0;
+        }
+
+        // Convert from density over the image to density over solid angle
+        self.distribution.pdf(u, v) / (2.0 * PI * PI * sin_theta)
+    }
+
+    fn sample(&self) -> Option<(LightSample, f32)> {
+        let (u, v, map_pdf) = self.distribution.sample();
+        let sin_theta = (PI * v).sin();
+        if map_pdf <= 0.0 || sin_theta <= 0.0 {
+            return None;
+        }
+
+        let sample = LightSample {
+            direction: self.uv_to_direction(u, v),
+            distance: f32::INFINITY,
+            radiance: self.lookup(u, v),
+        };
+
+        Some((sample, map_pdf / (2.0 * PI * PI * sin_theta)))
+    }
+}
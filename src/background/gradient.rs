@@ -0,0 +1,25 @@
+use crate::{color::Color, vec3::Vec3};
+
+use super::Background;
+
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    // Color straight down
+    bottom: Color,
+    // Color straight up
+    top: Color,
+}
+
+impl Gradient {
+    pub fn new(bottom: Color, top: Color) -> Gradient {
+        Gradient { bottom, top }
+    }
+}
+
+impl Background for Gradient {
+    fn value(&self, direction: &Vec3) -> Color {
+        // Blend linearly with the height of the unit direction
+        let a = 0.5 * (direction.unit_vector().y() + 1.0);
+        (1.0 - a) * self.bottom + a * self.top
+    }
+}
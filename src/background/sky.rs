@@ -0,0 +1,177 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use crate::{color::Color, light::LightType, vec3::Vec3};
+
+use super::Background;
+
+// Illuminance of the sun above the atmosphere, in the same kilo-units as the sky luminance
+const SOLAR_ILLUMINANCE: f32 = 128.0;
+// Angular radius of the sun's disk in degrees
+const SUN_ANGULAR_RADIUS: f32 = 0.27;
+
+#[derive(Debug, Clone, Copy)]
+struct Perez {
+    a: f32,
+    b: f32,
+    c: f32,
+    d: f32,
+    e: f32,
+}
+
+impl Perez {
+    fn f(&self, cos_theta: f32, gamma: f32) -> f32 {
+        // Perez sky luminance distribution for a view at theta from the zenith and gamma from
+        // the sun
+        let cos_gamma = gamma.cos();
+        (1.0 + self.a * (self.b / cos_theta).exp())
+            * (1.0 + self.c * (self.d * gamma).exp() + self.e * cos_gamma * cos_gamma)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Sky {
+    // Unit direction from the scene towards the sun
+    sun_direction: Vec3,
+    turbidity: f32,
+    ground_albedo: Color,
+    // Scale from the model's kcd/m^2 to scene radiance
+    intensity: f32,
+    // Perez coefficients for the luminance and the two chromaticity channels
+    perez: [Perez; 3],
+    // Zenith luminance and chromaticity divided by the Perez function at the zenith
+    zenith: [f32; 3],
+}
+
+impl Sky {
+    pub fn new(sun_direction: Vec3, turbidity: f32, ground_albedo: Color, intensity: f32) -> Sky {
+        // Analytic daylight after Preetham, Shirley & Smits, "A Practical Analytic Model for
+        // Daylight". Turbidity ranges from 2 for a very clear sky to 10 for a hazy one.
+        let sun_direction = sun_direction.unit_vector();
+        let t = turbidity.clamp(2.0, 10.0);
+
+        // The model is only valid while the sun is above the horizon
+        let theta_s = sun_direction
+            .y()
+            .clamp(0.0, 1.0)
+            .acos()
+            .min(FRAC_PI_2 - 0.01);
+
+        let perez = [
+            Perez {
+                a: 0.1787 * t - 1.4630,
+                b: -0.3554 * t + 0.4275,
+                c: -0.0227 * t + 5.3251,
+                d: 0.1206 * t - 2.5771,
+                e: -0.0670 * t + 0.3703,
+            },
+            Perez {
+                a: -0.0193 * t - 0.2592,
+                b: -0.0665 * t + 0.0008,
+                c: -0.0004 * t + 0.2125,
+                d: -0.0641 * t - 0.8989,
+                e: -0.0033 * t + 0.0452,
+            },
+            Perez {
+                a: -0.0167 * t - 0.2608,
+                b: -0.0950 * t + 0.0092,
+                c: -0.0079 * t + 0.2102,
+                d: -0.0441 * t - 1.6537,
+                e: -0.0109 * t + 0.0529,
+            },
+        ];
+
+        let chi = (4.0 / 9.0 - t / 120.0) * (PI - 2.0 * theta_s);
+        let zenith_luminance = (4.0453 * t - 4.9710) * chi.tan() - 0.2155 * t + 2.4192;
+
+        let (t2, th, th2, th3) = (t * t, theta_s, theta_s * theta_s, theta_s.powi(3));
+        let zenith_x = t2 * (0.00166 * th3 - 0.00375 * th2 + 0.00209 * th)
+            + t * (-0.02903 * th3 + 0.06377 * th2 - 0.03202 * th + 0.00394)
+            + (0.11693 * th3 - 0.21196 * th2 + 0.06052 * th + 0.25886);
+        let zenith_y = t2 * (0.00275 * th3 - 0.00610 * th2 + 0.00317 * th)
+            + t * (-0.04214 * th3 + 0.08970 * th2 - 0.04153 * th + 0.00516)
+            + (0.15346 * th3 - 0.26756 * th2 + 0.06670 * th + 0.26688);
+
+        let zenith = [zenith_luminance, zenith_x, zenith_y];
+        let zenith = std::array::from_fn(|i| zenith[i] / perez[i].f(1.0, theta_s));
+
+        Sky {
+            sun_direction,
+            turbidity: t,
+            ground_albedo,
+            intensity,
+            perez,
+            zenith,
+        }
+    }
+
+    pub fn sun(&self) -> LightType {
+        // The sun matching the sky, dimmed and reddened by the atmosphere it shines through
+        let cos_theta_s = self.sun_direction.y();
+        if cos_theta_s <= 0.0 {
+            return LightType::directional(-self.sun_direction, Color::default(), 0.0);
+        }
+
+        LightType::directional(
+            -self.sun_direction,
+            (self.intensity * SOLAR_ILLUMINANCE) * self.sun_transmittance(),
+            SUN_ANGULAR_RADIUS,
+        )
+    }
+
+    fn sun_transmittance(&self) -> Color {
+        // Rayleigh and aerosol extinction along the path of sunlight through the atmosphere for
+        // representative red, green and blue wavelengths in micrometers
+        let theta_s = self.sun_direction.y().clamp(0.0, 1.0).acos();
+        let relative_mass =
+            1.0 / (theta_s.cos() + 0.15 * (93.885 - theta_s.to_degrees()).powf(-1.253));
+        let beta = 0.04608 * self.turbidity - 0.04586;
+
+        let extinction = |lambda: f32| {
+            let rayleigh = (-0.008735 * lambda.powf(-4.08) * relative_mass).exp();
+            let aerosol = (-beta * lambda.powf(-1.3) * relative_mass).exp();
+            rayleigh * aerosol
+        };
+
+        Color::new(extinction(0.68), extinction(0.55), extinction(0.44))
+    }
+
+    fn sky_color(&self, cos_theta: f32, gamma: f32) -> Color {
+        let [luminance, x, y] =
+            std::array::from_fn(|i| self.zenith[i] * self.perez[i].f(cos_theta, gamma));
+
+        xyy_to_rgb(x, y, luminance)
+    }
+}
+
+impl Background for Sky {
+    fn value(&self, direction: &Vec3) -> Color {
+        let direction = direction.unit_vector();
+
+        // Below the horizon the ground reflects the light of the sun and the sky overhead
+        if direction.y() <= 0.0 {
+            let sun_irradiance =
+                SOLAR_ILLUMINANCE * self.sun_direction.y().max(0.0) * self.sun_transmittance();
+            let sky_irradiance = PI * self.sky_color(1.0, self.sun_direction.y().acos());
+            return (self.intensity / PI) * self.ground_albedo * (sun_irradiance + sky_irradiance);
+        }
+
+        let gamma = direction.dot(&self.sun_direction).clamp(-1.0, 1.0).acos();
+        self.intensity * self.sky_color(direction.y(), gamma)
+    }
+}
+
+fn xyy_to_rgb(x: f32, y: f32, luminance: f32) -> Color {
+    // CIE xyY chromaticity and luminance to linear sRGB
+    if y <= 0.0 {
+        return Color::default();
+    }
+
+    let big_x = x / y * luminance;
+    let big_z = (1.0 - x - y) / y * luminance;
+
+    Color::new(
+        (3.2406 * big_x - 1.5372 * luminance - 0.4986 * big_z).max(0.0),
+        (-0.9689 * big_x + 1.8758 * luminance + 0.0415 * big_z).max(0.0),
+        (0.0557 * big_x - 0.2040 * luminance + 1.0570 * big_z).max(0.0),
+    )
+}
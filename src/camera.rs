@@ -1,17 +1,36 @@
 use std::{f32::INFINITY, fs::File, io::Write, sync::Arc};
 
+use image::RgbImage;
 use indicatif::ParallelProgressIterator;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::{
+    background::BackgroundType,
     color::Color,
-    hittable::{HitRecord, Hittable},
+    hittable::{hittable_list::HittableList, HitRecord, Hittable, HittableObject},
+    interior::InteriorStack,
     interval::Interval,
+    light::{area::AreaLight, bvh::LightBvh, LightType},
+    material::{dialectric::Dialectric, MaterialType},
     ray::Ray,
     utility::{degree_to_radians, random_float},
     vec3::{Point3, Vec3},
 };
 
+pub mod aperture;
+pub mod lens;
+pub mod projection;
+pub mod sequence;
+pub mod shutter;
+pub mod stereo;
+
+use aperture::Aperture;
+use lens::LensSystem;
+use projection::Projection;
+use sequence::Sequence;
+use shutter::Shutter;
+use stereo::{Convergence, Stereo};
+
 const FILENAME: &str = "image.ppm";
 
 pub struct Camera {
@@ -23,9 +42,16 @@ pub struct Camera {
     pub samples_per_pixel: u32,
     // Maximum numbers of ray bounces into scene
     pub max_depth: u32,
-    // Scene background color
-    pub background: Color,
-
+    // Scene background seen by rays that escape the scene
+    pub background: BackgroundType,
+    // Light sources sampled directly at every diffuse bounce, one picked at a time except for
+    // distant lights
+    pub lights: Vec<LightType>,
+
+    // How rays leave the camera towards the scene
+    pub projection: Projection,
+    // Render a view for each eye side by side or over under, converging at `focus_dist`
+    pub stereo: Option<Stereo>,
     // Vertical view angle (field of view)
     pub vfov: f32,
     // Point camera is looking from
@@ -37,8 +63,12 @@ pub struct Camera {
 
     // Variation angle of rays through each pixel
     pub defocus_angle: f32,
+    // Shape of the lens opening, which out of focus highlights take on
+    pub aperture: Aperture,
     // Distance from camera lookfrom point to plane of perfect focus
     pub focus_dist: f32,
+    // When each row of the image is exposed, the times moving objects are seen at
+    pub shutter: Shutter,
 
     // Rendered image height
     image_height: u32,
@@ -60,6 +90,19 @@ pub struct Camera {
     defocus_disk_u: Vec3,
     // Defocus disk vertical radius
     defocus_disk_v: Vec3,
+    // Lens of a lens projection, moved to focus at the focus distance
+    lens: Option<LensSystem>,
+    // Hierarchy picking which of the lights to sample at each bounce
+    light_bvh: LightBvh,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DiffuseBounce {
+    // Where the ray was scattered, and the normal lights were picked for there
+    p: Point3,
+    normal: Vec3,
+    // Density of scattering in the direction of the ray
+    pdf: f32,
 }
 
 impl Camera {
@@ -72,50 +115,130 @@ impl Camera {
             .open(FILENAME)
             .unwrap();
 
-        let _ = file.write_fmt(format_args!(
-            "P3\n{} {}\n255\n",
-            self.image_width, self.image_height
-        ));
+        let (width, height) = self.output_size();
 
-        let arc = Arc::new(world);
+        let _ = file.write_fmt(format_args!("P3\n{} {}\n255\n", width, height));
 
         let start = std::time::Instant::now();
-        let bytes: Vec<_> = (0..self.image_height)
+        let rows = self.render_rows(world, width, height);
+        let end = std::time::Instant::now();
+
+        rows.iter().for_each(|row| {
+            let text: String = row.iter().map(Color::to_color).collect();
+            file.write_all(text.as_bytes()).unwrap()
+        });
+        println!("Time taken to run: {}", (end - start).as_secs());
+    }
+
+    pub fn render_sequence(&mut self, world: &HittableList, sequence: &Sequence) {
+        // Animated objects are bounded again around their motion in each frame, everything else
+        // is built into one hierarchy shared by all the frames
+        let mut still = vec![];
+        let mut animated = vec![];
+        for object in &world.objects {
+            match object {
+                HittableObject::Animated(object) => animated.push(object.clone()),
+                _ => still.push(object.clone()),
+            }
+        }
+        let still = (!still.is_empty()).then(|| HittableObject::bvh_node(&mut still));
+
+        // The shutter opens and closes relative to the start of each frame
+        let shutter = self.shutter.clone();
+
+        for frame in sequence.frames.clone() {
+            let time = sequence.time(frame);
+            sequence.pose_camera(self, time);
+            self.shutter = Shutter {
+                open: shutter.open + time,
+                close: shutter.close + time,
+                ..shutter.clone()
+            };
+            self.initialize();
+
+            let span = Interval::new(
+                self.shutter.open.min(self.shutter.close),
+                self.shutter.open.max(self.shutter.close) + self.shutter.readout.max(0.0),
+            );
+            let mut moving: Vec<_> = animated
+                .iter()
+                .map(|object| HittableObject::Animated(object.during(&span)))
+                .collect();
+
+            let mut frame_world = HittableList::default();
+            if let Some(still) = &still {
+                frame_world.add(still.clone());
+            }
+            if !moving.is_empty() {
+                frame_world.add(HittableObject::bvh_node(&mut moving));
+            }
+
+            let (width, height) = self.output_size();
+
+            let start = std::time::Instant::now();
+            let rows = self.render_rows(&frame_world, width, height);
+            let end = std::time::Instant::now();
+
+            let bytes = rows.iter().flatten().flat_map(|c| c.to_bytes()).collect();
+            let filename = sequence.filename(frame);
+            RgbImage::from_raw(width, height, bytes)
+                .expect("one pixel per image position")
+                .save(&filename)
+                .unwrap();
+            println!("{filename} taken: {}", (end - start).as_secs());
+        }
+
+        self.shutter = shutter;
+    }
+
+    fn output_size(&self) -> (u32, u32) {
+        // A stereo image holds a full size view for each eye
+        self.stereo
+            .map_or((self.image_width, self.image_height), |s| {
+                s.image_size(self.image_width, self.image_height)
+            })
+    }
+
+    fn render_rows(&self, world: &impl Hittable, width: u32, height: u32) -> Vec<Vec<Color>> {
+        let arc = Arc::new(world);
+
+        (0..height)
             .collect::<Vec<_>>()
             .par_iter()
             .progress()
             .map(|j| {
                 let world = arc.clone();
 
-                let mut bytes = vec![];
-                for i in 0..self.image_width {
+                let mut row = vec![];
+                for x in 0..width {
                     let mut pixel_color = Color::default();
+                    let (eye, i, j) = self.stereo.map_or((0.0, x, *j), |s| {
+                        s.eye_pixel(x, *j, self.image_width, self.image_height)
+                    });
 
                     for _sample in 0..self.samples_per_pixel {
-                        let ray = self.get_ray(i, *j);
-                        pixel_color += self.ray_color(&ray, self.max_depth, *world.as_ref());
+                        // Parts of the image that see nothing stay black
+                        let Some(ray) = self.get_ray(i, j, eye) else {
+                            continue;
+                        };
+                        pixel_color += self.ray_color(
+                            &ray,
+                            self.max_depth,
+                            *world.as_ref(),
+                            &InteriorStack::default(),
+                            None,
+                        );
                     }
-                    bytes.extend_from_slice(
-                        format!(
-                            "{}",
-                            (self.pixel_sample_scale as f32 * pixel_color).to_color()
-                        )
-                        .as_bytes(),
-                    );
+                    row.push(self.pixel_sample_scale as f32 * pixel_color);
                 }
-                bytes
+                row
             })
-            .collect();
-        let end = std::time::Instant::now();
-
-        bytes
-            .iter()
-            .for_each(|bytes| file.write_all(bytes).unwrap());
-        println!("Time taken to run: {}", (end - start).as_secs());
+            .collect()
     }
 
     fn initialize(&mut self) {
-        self.image_height = (self.image_width as f32 / self.aspect_ratio) as u32;
+        let aspect_ratio = self.projection.aspect_ratio().unwrap_or(self.aspect_ratio);
+        self.image_height = (self.image_width as f32 / aspect_ratio) as u32;
         self.image_height = if self.image_height < 1 {
             1
         } else {
@@ -134,7 +257,10 @@ impl Camera {
         // Determine Viewport dimensions
         let theta = degree_to_radians(self.vfov);
         let h = (theta / 2.0).tan();
-        let viewport_height = 2.0 * h * self.focus_dist;
+        let viewport_height = match self.projection {
+            Projection::Orthographic { view_height } => view_height,
+            _ => 2.0 * h * self.focus_dist,
+        };
         let viewport_width = viewport_height * (self.image_width as f32 / self.image_height as f32);
 
         // Calculate the u, v, w unit basis vectors for the camera coordinate frame.
@@ -159,26 +285,122 @@ impl Camera {
         let defocus_radius = self.focus_dist * degree_to_radians(self.defocus_angle / 2.0).tan();
         self.defocus_disk_u = self.u * defocus_radius;
         self.defocus_disk_v = self.v * defocus_radius;
+
+        // A real lens focuses by moving, and lets less light through than a pinhole, so the
+        // image is brightened to make up for it
+        self.lens = match &self.projection {
+            Projection::Lens { lens, .. } => Some(lens.focused(self.focus_dist)),
+            _ => None,
+        };
+        if let Some(lens) = &self.lens {
+            let transmission = lens.axial_transmission();
+            if transmission > 0.0 {
+                self.pixel_sample_scale /= transmission;
+            }
+        }
+
+        self.light_bvh = LightBvh::new(&self.lights);
     }
 
-    fn get_ray(&self, i: u32, j: u32) -> Ray {
+    fn get_ray(&self, i: u32, j: u32, eye: f32) -> Option<Ray> {
         // Construct a camera Ray originating from the defocus disk and directed at randomly sampled
-        // point around the pixel location i, j
+        // point around the pixel location i, j, for the eye `eye` to the right of the center
 
         let offset = Self::sample_square();
+
+        let s = (i as f32 + 0.5 + offset.x()) / self.image_width as f32;
+        let t = (j as f32 + 0.5 + offset.y()) / self.image_height as f32;
+        let aspect_ratio = self.image_width as f32 / self.image_height as f32;
+        let ray_time = self.shutter.sample(t);
+
+        // A lens bends rays from the film through each of its elements in turn
+        if let (Some(lens), Projection::Lens { film_diagonal, .. }) = (&self.lens, &self.projection)
+        {
+            let ray = lens.get_ray(s, t, aspect_ratio, *film_diagonal)?;
+            let ray_origin = self.center + self.camera_to_world(ray.origin());
+            let ray_direction = self.camera_to_world(ray.direction());
+            return Some(Ray::new(&ray_origin, &ray_direction, ray_time));
+        }
+
+        // Panoramas and fisheyes map the image onto directions around the camera center
+        if !self.projection.is_planar() {
+            let direction = self.projection.direction(s, t, aspect_ratio)?;
+
+            let ray_direction = self.camera_to_world(&direction);
+            let (ray_origin, ray_direction) = self.panorama_eye(&ray_direction, eye);
+            return Some(Ray::new(&ray_origin, &ray_direction, ray_time));
+        }
+
         let pixel_sample = self.pixel00_loc
             + ((i as f32 + offset.x()) * self.pixel_delta_u)
             + ((j as f32 + offset.y()) * self.pixel_delta_v);
 
+        // Orthographic rays start level with the camera center and travel parallel to its axis
+        let lens_center = match self.projection {
+            Projection::Orthographic { .. } => pixel_sample + self.focus_dist * self.w,
+            _ => self.center,
+        };
+        let (lens_center, pixel_sample) = self.planar_eye(lens_center, pixel_sample, eye);
+
         let ray_origin = if self.defocus_angle <= 0.0 {
-            self.center
+            lens_center
         } else {
-            self.defocus_disk_sample()
+            self.defocus_disk_sample(&lens_center)
         };
         let ray_direction = pixel_sample - ray_origin;
-        let ray_time = random_float();
 
-        Ray::new(&ray_origin, &ray_direction, ray_time)
+        Some(Ray::new(&ray_origin, &ray_direction, ray_time))
+    }
+
+    fn camera_to_world(&self, v: &Vec3) -> Vec3 {
+        (v.x() * self.u) + (v.y() * self.v) + (v.z() * self.w)
+    }
+
+    fn planar_eye(&self, lens_center: Point3, pixel_sample: Point3, eye: f32) -> (Point3, Point3) {
+        // Move the lens and the pixel from the camera center to the eye
+        let Some(stereo) = self.stereo else {
+            return (lens_center, pixel_sample);
+        };
+
+        let shift = eye * self.u;
+        match stereo.convergence {
+            Convergence::Parallel => (lens_center + shift, pixel_sample + shift),
+            Convergence::OffAxis => (lens_center + shift, pixel_sample),
+            Convergence::ToeIn => {
+                // Turn the whole camera about its vertical axis to face the convergence point
+                let eye_center = self.center + shift;
+                let target = self.center - self.focus_dist * self.w;
+                let w = (eye_center - target).unit_vector();
+                let u = self.v.cross(&w);
+
+                let to_eye = |p: Point3| {
+                    let d = p - self.center;
+                    eye_center
+                        + (d.dot(&self.u) * u)
+                        + (d.dot(&self.v) * self.v)
+                        + (d.dot(&self.w) * w)
+                };
+                (to_eye(lens_center), to_eye(pixel_sample))
+            }
+        }
+    }
+
+    fn panorama_eye(&self, direction: &Vec3, eye: f32) -> (Point3, Vec3) {
+        // Omnidirectional stereo, where the eyes sit either side of the center across every
+        // direction looked in, as if turning the head to look that way. The eyes come together
+        // towards straight up and down, where there is no across to separate them along.
+        let Some(stereo) = self.stereo else {
+            return (self.center, *direction);
+        };
+
+        let across = direction.unit_vector().cross(&self.v);
+        let origin = self.center + eye * across;
+        let direction = match stereo.convergence {
+            Convergence::Parallel => *direction,
+            _ => (self.center + self.focus_dist * direction.unit_vector()) - origin,
+        };
+
+        (origin, direction)
     }
 
     fn sample_square() -> Vec3 {
@@ -186,37 +408,278 @@ impl Camera {
         Vec3::new(random_float() - 0.5, random_float() - 0.5, 0.0)
     }
 
-    fn defocus_disk_sample(&self) -> Vec3 {
+    fn defocus_disk_sample(&self, lens_center: &Point3) -> Vec3 {
         // Returns a random point on the defocus disk
-        let p = Vec3::random_in_unit_disk();
-        self.center + (p.x() * self.defocus_disk_u) + (p.y() * self.defocus_disk_v)
+        let p = self.aperture.sample();
+        *lens_center + (p.x() * self.defocus_disk_u) + (p.y() * self.defocus_disk_v)
     }
 
-    fn ray_color(&self, r: &Ray, depth: u32, world: &impl Hittable) -> Color {
+    fn ray_color(
+        &self,
+        r: &Ray,
+        depth: u32,
+        world: &impl Hittable,
+        interiors: &InteriorStack,
+        bounce: Option<DiffuseBounce>,
+    ) -> Color {
         // If we hit the max ray bounce limit, no more light is gathered.
-        if depth <= 0 {
+        if depth == 0 {
             return Color::default();
         }
 
         let mut rec = HitRecord::default();
 
-        // If the ray hits nothing, it returns the background color.
+        // If the ray hits nothing, it returns the background color. After a diffuse bounce the
+        // background was also sampled directly, so the two estimates are weighted against each
+        // other.
         if !world.hit(r, &Interval::new(0.001, INFINITY), &mut rec) {
-            return self.background;
+            let background = self.background.value(r.direction());
+            return match bounce {
+                Some(bounce) => {
+                    power_heuristic(bounce.pdf, self.background.pdf_value(r.direction()))
+                        * background
+                }
+                None => background,
+            };
         }
 
+        // Light reaching the hit point is absorbed by the medium the ray travelled through.
+        let transmittance = interiors
+            .current()
+            .transmittance(rec.t * r.direction().length());
+
         let mut scattered = Ray::default();
         let mut attenuation = Color::default();
 
-        let color_from_emission = rec.mat.emitted(rec.u, rec.v, &rec.p);
+        if let MaterialType::Dialectric(dialectric) = rec.mat.as_ref() {
+            return transmittance
+                * self.dialectric_color(r, &rec, dialectric, depth, world, interiors);
+        }
+
+        // Emitters that are also lights were sampled directly at the previous bounce.
+        let mut color_from_emission = rec.mat.emitted(r, &rec, rec.u, rec.v, &rec.p);
+        if let Some(bounce) = bounce {
+            color_from_emission = self.emission_weight(r, &rec, &bounce) * color_from_emission;
+        }
 
         if !rec.mat.scatter(r, &rec, &mut attenuation, &mut scattered) {
-            return color_from_emission;
+            return transmittance * color_from_emission;
+        }
+
+        let color_from_lights = attenuation * self.sample_lights(r, &rec, world);
+
+        let scattering_pdf = rec.mat.scattering_pdf(r, &rec, &scattered);
+        let bounce = DiffuseBounce {
+            p: rec.p,
+            normal: light_selection_normal(&rec),
+            pdf: scattering_pdf,
+        };
+        let color_from_scatter = attenuation
+            * self.ray_color(
+                &scattered,
+                depth - 1,
+                world,
+                interiors,
+                (scattering_pdf > 0.0).then_some(bounce),
+            );
+
+        transmittance * (color_from_emission + color_from_lights + color_from_scatter)
+    }
+
+    fn sample_lights(&self, r: &Ray, rec: &HitRecord, world: &impl Hittable) -> Color {
+        // Gather the light arriving directly from every distant light, and from one of the other
+        // lights picked by how much it is expected to contribute.
+        let mut color_from_lights = Color::default();
+
+        for light in self.lights.iter().filter(|light| light.is_infinite()) {
+            color_from_lights += self.sample_delta_light(r, rec, world, light);
+        }
+
+        let normal = light_selection_normal(rec);
+        if let Some((index, pmf)) = self.light_bvh.sample(&rec.p, &normal) {
+            color_from_lights += match &self.lights[index] {
+                LightType::Area(light) => self.sample_area_light(r, rec, world, light, pmf),
+                light => self.sample_delta_light(r, rec, world, light) / pmf,
+            };
+        }
+
+        // Sample the brightest parts of the background the same way, weighted against the
+        // chance of finding them by scattering.
+        if let Some((sample, light_pdf)) = self.background.sample() {
+            let shadow_ray = Ray::new(&rec.p, &sample.direction, r.time());
+            let scattering_pdf = rec.mat.scattering_pdf(r, rec, &shadow_ray);
+
+            let mut blocker = HitRecord::default();
+            if scattering_pdf > 0.0
+                && !world.hit(
+                    &shadow_ray,
+                    &Interval::new(0.001, sample.distance - 0.001),
+                    &mut blocker,
+                )
+            {
+                let weight = power_heuristic(light_pdf, scattering_pdf);
+                color_from_lights += (weight * scattering_pdf / light_pdf) * sample.radiance;
+            }
+        }
+
+        color_from_lights
+    }
+
+    fn sample_delta_light(
+        &self,
+        r: &Ray,
+        rec: &HitRecord,
+        world: &impl Hittable,
+        light: &LightType,
+    ) -> Color {
+        let Some(sample) = light.sample(&rec.p) else {
+            return Color::default();
+        };
+
+        let shadow_ray = Ray::new(&rec.p, &sample.direction, r.time());
+        let scattering_pdf = rec.mat.scattering_pdf(r, rec, &shadow_ray);
+        if scattering_pdf <= 0.0 {
+            return Color::default();
+        }
+
+        let mut blocker = HitRecord::default();
+        if world.hit(
+            &shadow_ray,
+            &Interval::new(0.001, sample.distance - 0.001),
+            &mut blocker,
+        ) {
+            return Color::default();
+        }
+
+        scattering_pdf * sample.radiance
+    }
+
+    fn sample_area_light(
+        &self,
+        r: &Ray,
+        rec: &HitRecord,
+        world: &impl Hittable,
+        light: &AreaLight,
+        pmf: f32,
+    ) -> Color {
+        // Light from a point on an emissive surface, weighted against the chance of finding it by
+        // scattering.
+        let direction = light.random(&rec.p);
+        let shadow_ray = Ray::new(&rec.p, &direction, r.time());
+
+        let light_pdf = pmf * light.pdf_value(&rec.p, &direction);
+        let scattering_pdf = rec.mat.scattering_pdf(r, rec, &shadow_ray);
+        if light_pdf <= 0.0 || scattering_pdf <= 0.0 {
+            return Color::default();
+        }
+
+        // The light is only visible if it is the first surface the ray hits.
+        let mut emitter = HitRecord::default();
+        if !world.hit(
+            &shadow_ray,
+            &Interval::new(0.001, f32::INFINITY),
+            &mut emitter,
+        ) || !light.is_emitter(&emitter.mat)
+        {
+            return Color::default();
+        }
+
+        let emitted = emitter
+            .mat
+            .emitted(&shadow_ray, &emitter, emitter.u, emitter.v, &emitter.p);
+        let weight = power_heuristic(light_pdf, scattering_pdf);
+
+        (weight * scattering_pdf / light_pdf) * emitted
+    }
+
+    fn emission_weight(&self, r: &Ray, rec: &HitRecord, bounce: &DiffuseBounce) -> f32 {
+        // Weight of light found by scattering from a surface that could also have been sampled
+        // as a light at the bounce the ray came from
+        let Some(index) = self.light_bvh.emitter(&rec.mat) else {
+            return 1.0;
+        };
+        let LightType::Area(light) = &self.lights[index] else {
+            return 1.0;
+        };
+
+        let light_pdf = self.light_bvh.pmf(&bounce.p, &bounce.normal, index)
+            * light.pdf_value(&bounce.p, r.direction());
+
+        power_heuristic(bounce.pdf, light_pdf)
+    }
+
+    fn dialectric_color(
+        &self,
+        r: &Ray,
+        rec: &HitRecord,
+        dialectric: &Dialectric,
+        depth: u32,
+        world: &impl Hittable,
+        interiors: &InteriorStack,
+    ) -> Color {
+        // Determine the media on both sides of the surface from the interiors the ray is in.
+        let interior = dialectric.interior();
+        let (beyond, incident_index) = if rec.front_face {
+            (
+                interiors.entered(&interior),
+                interiors.current().refractive_index,
+            )
+        } else {
+            (interiors.exited(&interior), interior.refractive_index)
+        };
+
+        // A surface inside a higher priority medium does not exist for the ray, so it carries on
+        // unchanged into whatever lies beyond it.
+        let outside = if rec.front_face { interiors } else { &beyond };
+        if !outside.is_boundary(&interior) {
+            let continued = Ray::new(&rec.p, r.direction(), r.time());
+            return self.ray_color(&continued, depth - 1, world, &beyond, None);
         }
 
-        let color_from_scatter = attenuation * self.ray_color(&scattered, depth - 1, world);
+        let transmitted_index = if rec.front_face {
+            interior.refractive_index
+        } else {
+            beyond.current().refractive_index
+        };
+
+        let mut scattered = Ray::default();
+        let mut attenuation = Color::default();
+        dialectric.scatter_between(
+            r,
+            rec,
+            incident_index / transmitted_index,
+            &mut attenuation,
+            &mut scattered,
+        );
+
+        // Only a refracted ray crosses into the media on the other side of the surface.
+        let next = if scattered.direction().dot(&rec.normal) < 0.0 {
+            &beyond
+        } else {
+            interiors
+        };
+
+        attenuation * self.ray_color(&scattered, depth - 1, world, next, None)
+    }
+}
+
+fn light_selection_normal(rec: &HitRecord) -> Vec3 {
+    // Scattering inside a medium doesn't favour any direction, so there's no normal to weigh
+    // lights by
+    match rec.mat.as_ref() {
+        MaterialType::Isotropic(_) => Vec3::default(),
+        _ => rec.normal,
+    }
+}
 
-        color_from_scatter
+fn power_heuristic(pdf: f32, other_pdf: f32) -> f32 {
+    // Multiple importance sampling weight of a sample drawn with `pdf` that could also have been
+    // drawn with `other_pdf`
+    let (pdf, other_pdf) = (pdf * pdf, other_pdf * other_pdf);
+    if pdf + other_pdf > 0.0 {
+        pdf / (pdf + other_pdf)
+    } else {
+        0.0
     }
 }
 
@@ -227,13 +690,18 @@ impl Default for Camera {
             image_width: 100,
             samples_per_pixel: 10,
             max_depth: 10,
-            background: Color::default(),
+            background: Color::default().into(),
+            lights: vec![],
+            projection: Projection::default(),
+            stereo: None,
             vfov: 90.0,
             look_from: Point3::default(),
             look_at: Point3::new(0.0, 0.0, -1.0),
             vup: Vec3::new(0.0, 1.0, 0.0),
             defocus_angle: 0.0,
+            aperture: Aperture::default(),
             focus_dist: 10.0,
+            shutter: Shutter::default(),
             image_height: 0,
             pixel_sample_scale: 0.0,
             center: Point3::default(),
@@ -245,6 +713,8 @@ impl Default for Camera {
             w: Vec3::default(),
             defocus_disk_u: Vec3::default(),
             defocus_disk_v: Vec3::default(),
+            lens: None,
+            light_bvh: LightBvh::default(),
         }
     }
 }
@@ -0,0 +1,108 @@
+use std::{f32::consts::PI, sync::Arc};
+
+use image::DynamicImage;
+
+use crate::{
+    distribution::Distribution2D,
+    utility::{degree_to_radians, random_float},
+    vec3::Vec3,
+};
+
+#[derive(Clone, Default)]
+pub enum Aperture {
+    // Perfectly round opening, giving round bokeh
+    #[default]
+    Circle,
+    // Opening formed by `blades` straight diaphragm blades, rotated by `rotation` degrees
+    Polygon {
+        blades: u32,
+        rotation: f32,
+    },
+    // Mask stretched over the square around the defocus disk, brighter pixels letting more light
+    // through
+    Mask(Arc<Distribution2D>),
+}
+
+impl Aperture {
+    pub fn polygon(blades: u32, rotation: f32) -> Aperture {
+        Aperture::Polygon { blades, rotation }
+    }
+
+    pub fn mask(image: DynamicImage) -> Aperture {
+        let image = image.to_luma32f();
+        let (width, height) = (image.width() as usize, image.height() as usize);
+        let weights: Vec<f32> = image.pixels().map(|pixel| pixel[0]).collect();
+
+        Aperture::Mask(Arc::new(Distribution2D::new(&weights, width, height)))
+    }
+
+    pub fn sample(&self) -> Vec3 {
+        // Returns a random point of the opening, which fits in [-1, -1]-[1, 1]
+        match self {
+            Aperture::Circle => Vec3::random_in_unit_disk(),
+            Aperture::Polygon { blades, rotation } if *blades >= 3 => {
+                // Pick one of the equal triangles between the center and the edges, then a point
+                // in it
+                let step = 2.0 * PI / *blades as f32;
+                let edge = (random_float() * *blades as f32)
+                    .floor()
+                    .min(*blades as f32 - 1.0);
+                let a0 = degree_to_radians(*rotation) + edge * step;
+                let a1 = a0 + step;
+
+                let (mut s, mut t) = (random_float(), random_float());
+                if s + t > 1.0 {
+                    (s, t) = (1.0 - s, 1.0 - t);
+                }
+
+                Vec3::new(
+                    s * a0.cos() + t * a1.cos(),
+                    s * a0.sin() + t * a1.sin(),
+                    0.0,
+                )
+            }
+            Aperture::Polygon { .. } => Vec3::random_in_unit_disk(),
+            Aperture::Mask(distribution) => {
+                let (u, v, _) = distribution.sample();
+                Vec3::new(2.0 * u - 1.0, 1.0 - 2.0 * v, 0.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{GrayImage, Luma};
+
+    use super::*;
+
+    #[test]
+    fn polygon_samples_stay_inside_the_blades() {
+        let (blades, rotation) = (5, 18.0);
+        let aperture = Aperture::polygon(blades, rotation);
+        let step = 2.0 * PI / blades as f32;
+        let apothem = (step / 2.0).cos();
+
+        for _ in 0..10_000 {
+            let p = aperture.sample();
+            for edge in 0..blades {
+                // Distance along the normal of each edge, through the middle of its two corners
+                let angle = degree_to_radians(rotation) + (edge as f32 + 0.5) * step;
+                let normal = Vec3::new(angle.cos(), angle.sin(), 0.0);
+                assert!(p.dot(&normal) <= apothem + 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn mask_samples_stay_in_the_open_pixels() {
+        // Only the top right pixel lets light through
+        let image = GrayImage::from_fn(4, 4, |x, y| Luma([if (x, y) == (3, 0) { 255 } else { 0 }]));
+        let aperture = Aperture::mask(DynamicImage::ImageLuma8(image));
+
+        for _ in 0..10_000 {
+            let p = aperture.sample();
+            assert!((0.5..=1.0).contains(&p.x()) && (0.5..=1.0).contains(&p.y()));
+        }
+    }
+}
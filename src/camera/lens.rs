@@ -0,0 +1,311 @@
+use std::io::{Error, ErrorKind};
+
+use crate::{
+    ray::Ray,
+    utility::read_asset,
+    vec3::{Point3, Vec3},
+};
+
+// Scene units are metres, lens prescriptions are in millimetres
+const MM_PER_UNIT: f32 = 1000.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LensElement {
+    // Radius of curvature of the surface, positive when its center lies towards the film, zero
+    // for the aperture stop
+    pub curvature_radius: f32,
+    // Distance along the axis to the next surface, or to the film for the last one
+    pub thickness: f32,
+    // Refractive index of the medium between this surface and the next, zero or one for air
+    pub ior: f32,
+    // Radius of the clear part of the surface
+    pub aperture_radius: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct LensSystem {
+    // Surfaces from the front of the lens to the back, in millimetres
+    elements: Vec<LensElement>,
+}
+
+impl LensSystem {
+    pub fn load(filename: &str) -> Result<LensSystem, Error> {
+        Self::parse(&read_asset(filename)?)
+    }
+
+    pub fn parse(text: &str) -> Result<LensSystem, Error> {
+        // One surface per line from the front of the lens, as radius, thickness, refractive index
+        // and aperture diameter in millimetres. Lines starting with '#' are comments.
+        let elements = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let values = line
+                    .split_whitespace()
+                    .map(|value| value.parse::<f32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| invalid(&e.to_string()))?;
+
+                let [curvature_radius, thickness, ior, aperture] = values[..] else {
+                    return Err(invalid(&format!("expected 4 values, found '{line}'")));
+                };
+
+                Ok(LensElement {
+                    curvature_radius,
+                    thickness,
+                    ior,
+                    aperture_radius: aperture / 2.0,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if elements.is_empty() {
+            return Err(invalid("no lens elements"));
+        }
+
+        Ok(LensSystem { elements })
+    }
+
+    pub fn focused(&self, focus_distance: f32) -> LensSystem {
+        // Move the lens away from the film until a point `focus_distance` in front of the film
+        // is sharp. The field of view narrows as the lens moves out, like a real lens breathing.
+        let mut tmp = self.clone();
+        let focus_distance = focus_distance * MM_PER_UNIT;
+
+        for _ in 0..16 {
+            let front_z = tmp.front_z();
+            let height = 0.1 * tmp.elements[0].aperture_radius;
+            let object = Point3::new(0.0, 0.0, -focus_distance);
+            let to_lens = Point3::new(height, 0.0, front_z) - object;
+
+            let Some(out) = tmp.trace_from_scene(&Ray::new(&object, &to_lens, 0.0)) else {
+                break;
+            };
+            if out.direction().x() == 0.0 {
+                break;
+            }
+
+            // Where the ray crosses the axis behind the lens is where the image is sharp
+            let t = -out.origin().x() / out.direction().x();
+            let image_z = out.at(t).z();
+            if let Some(last) = tmp.elements.last_mut() {
+                last.thickness = (last.thickness + image_z).max(0.0);
+            }
+            if image_z.abs() < 1e-4 {
+                break;
+            }
+        }
+
+        tmp
+    }
+
+    pub fn axial_transmission(&self) -> f32 {
+        // Fraction of rays from the center of the film that make it through the lens, used to
+        // keep the exposure of the image center independent of the lens
+        let samples = 4096;
+        let passed = (0..samples)
+            .filter(|_| {
+                let film = Point3::new(0.0, 0.0, 0.0);
+                let rear = self.sample_rear_element();
+                self.trace_from_film(&Ray::new(&film, &(rear - film), 0.0))
+                    .is_some()
+            })
+            .count();
+
+        passed as f32 / samples as f32
+    }
+
+    pub fn get_ray(&self, s: f32, t: f32, aspect_ratio: f32, film_diagonal: f32) -> Option<Ray> {
+        // Ray leaving the front of the lens for the image position (s, t) in [0, 1]^2, t
+        // increasing downwards, on a film with the given diagonal in millimetres. The ray is in
+        // the camera frame of x to the right, y up and z backwards with the film at the origin,
+        // None when the lens blocks it.
+        let film_height = film_diagonal / (1.0 + aspect_ratio * aspect_ratio).sqrt();
+        let film_width = aspect_ratio * film_height;
+
+        // The lens forms an upside down image on the film
+        let film = Point3::new((0.5 - s) * film_width, (t - 0.5) * film_height, 0.0);
+        let rear = self.sample_rear_element();
+
+        let out = self.trace_from_film(&Ray::new(&film, &(rear - film), 0.0))?;
+
+        Some(Ray::new(
+            &(*out.origin() / MM_PER_UNIT),
+            out.direction(),
+            0.0,
+        ))
+    }
+
+    fn front_z(&self) -> f32 {
+        -self.elements.iter().map(|e| e.thickness).sum::<f32>()
+    }
+
+    fn sample_rear_element(&self) -> Point3 {
+        let rear = self.elements[self.elements.len() - 1];
+        let p = Vec3::random_in_unit_disk();
+        Point3::new(
+            rear.aperture_radius * p.x(),
+            rear.aperture_radius * p.y(),
+            -rear.thickness,
+        )
+    }
+
+    fn trace_from_film(&self, ray: &Ray) -> Option<Ray> {
+        let mut ray = ray.clone();
+        let mut element_z = 0.0;
+
+        for (i, element) in self.elements.iter().enumerate().rev() {
+            element_z -= element.thickness;
+
+            // Leaving the medium after this surface for the medium in front of it
+            let eta_i = medium_ior(element.ior);
+            let eta_t = if i > 0 {
+                medium_ior(self.elements[i - 1].ior)
+            } else {
+                1.0
+            };
+
+            ray = Self::cross_surface(&ray, element, element_z, eta_i / eta_t)?;
+        }
+
+        Some(ray)
+    }
+
+    fn trace_from_scene(&self, ray: &Ray) -> Option<Ray> {
+        let mut ray = ray.clone();
+        let mut element_z = self.front_z();
+
+        for (i, element) in self.elements.iter().enumerate() {
+            let eta_i = if i > 0 {
+                medium_ior(self.elements[i - 1].ior)
+            } else {
+                1.0
+            };
+            let eta_t = medium_ior(element.ior);
+
+            ray = Self::cross_surface(&ray, element, element_z, eta_i / eta_t)?;
+            element_z += element.thickness;
+        }
+
+        Some(ray)
+    }
+
+    fn cross_surface(ray: &Ray, element: &LensElement, element_z: f32, eta: f32) -> Option<Ray> {
+        // Refract the ray through the surface whose vertex is at `element_z`, None when it misses
+        // the clear aperture or reflects totally
+        if element.curvature_radius == 0.0 {
+            // The aperture stop is a flat opening
+            let t = (element_z - ray.origin().z()) / ray.direction().z();
+            let p = ray.at(t);
+            if t < 0.0 || p.x() * p.x() + p.y() * p.y() > element.aperture_radius.powi(2) {
+                return None;
+            }
+            return Some(Ray::new(&p, ray.direction(), ray.time()));
+        }
+
+        let (t, normal) = intersect_spherical(
+            element.curvature_radius,
+            element_z + element.curvature_radius,
+            ray,
+        )?;
+        let p = ray.at(t);
+        if p.x() * p.x() + p.y() * p.y() > element.aperture_radius.powi(2) {
+            return None;
+        }
+
+        let direction = refract(&ray.direction().unit_vector(), &normal, eta)?;
+        Some(Ray::new(&p, &direction, ray.time()))
+    }
+}
+
+fn medium_ior(ior: f32) -> f32 {
+    if ior == 0.0 {
+        1.0
+    } else {
+        ior
+    }
+}
+
+fn intersect_spherical(radius: f32, z_center: f32, ray: &Ray) -> Option<(f32, Vec3)> {
+    // Hit of the ray with the part of the sphere that forms the lens surface, and the normal
+    // there facing back along the ray
+    let o = *ray.origin() - Vec3::new(0.0, 0.0, z_center);
+    let d = ray.direction();
+
+    let a = d.length_squared();
+    let half_b = o.dot(d);
+    let c = o.length_squared() - radius * radius;
+    let discriminant = half_b * half_b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let sqrtd = discriminant.sqrt();
+    let (t0, t1) = ((-half_b - sqrtd) / a, (-half_b + sqrtd) / a);
+
+    // The surface is the near or far side of the sphere depending on which way it curves
+    let use_closer = (d.z() > 0.0) ^ (radius < 0.0);
+    let t = if use_closer { t0.min(t1) } else { t0.max(t1) };
+    if t < 0.0 {
+        return None;
+    }
+
+    let normal = (o + t * *d).unit_vector();
+    let normal = if normal.dot(d) > 0.0 { -normal } else { normal };
+
+    Some((t, normal))
+}
+
+fn refract(unit_direction: &Vec3, normal: &Vec3, eta: f32) -> Option<Vec3> {
+    // Snell's law where `normal` faces against the direction and eta is the ratio of the
+    // refractive indices, None on total internal reflection
+    let cos_theta_i = (-*unit_direction).dot(normal);
+    let sin2_theta_t = eta * eta * (1.0 - cos_theta_i * cos_theta_i).max(0.0);
+    if sin2_theta_t >= 1.0 {
+        return None;
+    }
+
+    let cos_theta_t = (1.0 - sin2_theta_t).sqrt();
+    Some(eta * *unit_direction + (eta * cos_theta_i - cos_theta_t) * *normal)
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("invalid lens prescription: {message}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A single biconvex lens with a stop in front of it
+    const SINGLET: &str = "# radius thickness ior aperture\n\
+        0 1 0 10\n\
+        50 4 1.5 12\n\
+        -50 0 1 12\n";
+
+    #[test]
+    fn parses_prescription() {
+        let lens = LensSystem::parse(SINGLET).unwrap();
+        assert_eq!(lens.elements.len(), 3);
+        assert_eq!(lens.elements[1].aperture_radius, 6.0);
+        assert!(LensSystem::parse("10 1 1.5").is_err());
+    }
+
+    #[test]
+    fn focuses_near_the_focal_length() {
+        // A thin lens with both radii 50mm and index 1.5 has a focal length of 50mm
+        let lens = LensSystem::parse(SINGLET).unwrap();
+
+        let far = lens.focused(1000.0);
+        let film_distance = far.elements.last().unwrap().thickness;
+        assert!((film_distance - 50.0).abs() < 2.0, "{film_distance}");
+
+        // Focusing closer moves the lens away from the film
+        let near = lens.focused(0.5);
+        assert!(near.elements.last().unwrap().thickness > film_distance);
+    }
+}
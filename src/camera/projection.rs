@@ -0,0 +1,224 @@
+use std::{f32::consts::PI, sync::Arc};
+
+use crate::{utility::degree_to_radians, vec3::Vec3};
+
+use super::lens::LensSystem;
+
+#[derive(Debug, Clone, Default)]
+pub enum Projection {
+    // Pinhole camera seeing the scene in perspective over the vertical field of view `vfov`
+    #[default]
+    Perspective,
+    // Parallel rays without foreshortening, seeing `view_height` world units from top to bottom
+    Orthographic {
+        view_height: f32,
+    },
+    // Full sphere of directions in a 2:1 latitude-longitude image, looking at the center
+    Equirectangular,
+    // Six 90 degree faces in a 3:2 grid, right, left and up on the top row and down, front and
+    // back on the bottom row
+    CubeMap,
+    // Circular fisheye lens covering `fov` degrees, up to 360, across the image circle that fits
+    // the image. Nothing is seen outside the circle.
+    Fisheye {
+        mapping: FisheyeMapping,
+        fov: f32,
+    },
+    // Rays traced through the elements of a real lens onto a film with the given diagonal in
+    // millimetres, focused at the camera's `focus_dist`. The lens sets the field of view.
+    Lens {
+        lens: Arc<LensSystem>,
+        film_diagonal: f32,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum FisheyeMapping {
+    // Distance from the center proportional to the angle from the axis
+    Equidistant,
+    // Equal solid angles cover equal areas of the image
+    Equisolid,
+    // Preserves shapes locally, cannot cover a full 360 degrees
+    Stereographic,
+}
+
+impl Projection {
+    pub fn aspect_ratio(&self) -> Option<f32> {
+        // Panoramas fix the shape of the image, overriding the camera's aspect ratio
+        match self {
+            Projection::Equirectangular => Some(2.0),
+            Projection::CubeMap => Some(1.5),
+            _ => None,
+        }
+    }
+
+    pub fn is_planar(&self) -> bool {
+        // Planar projections trace rays through the camera's viewport instead of `direction`
+        matches!(
+            self,
+            Projection::Perspective | Projection::Orthographic { .. }
+        )
+    }
+
+    pub fn direction(&self, s: f32, t: f32, aspect_ratio: f32) -> Option<Vec3> {
+        // Direction through the image position (s, t) in [0, 1]^2, t increasing downwards, in the
+        // camera frame of x to the right, y up and z backwards. None where nothing is seen.
+        match self {
+            Projection::Equirectangular => Some(equirectangular_direction(s, t)),
+            Projection::CubeMap => Some(cube_map_direction(s, t)),
+            Projection::Fisheye { mapping, fov } => {
+                fisheye_direction(*mapping, *fov, s, t, aspect_ratio)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn equirectangular_direction(s: f32, t: f32) -> Vec3 {
+    let longitude = (s - 0.5) * 2.0 * PI;
+    let latitude = (0.5 - t) * PI;
+
+    Vec3::new(
+        latitude.cos() * longitude.sin(),
+        latitude.sin(),
+        -latitude.cos() * longitude.cos(),
+    )
+}
+
+fn cube_map_direction(s: f32, t: f32) -> Vec3 {
+    let column = ((s * 3.0) as usize).min(2);
+    let row = ((t * 2.0) as usize).min(1);
+
+    // Position across the face from -1 to 1, right and down
+    let a = 2.0 * (s * 3.0 - column as f32) - 1.0;
+    let b = 2.0 * (t * 2.0 - row as f32) - 1.0;
+
+    let right = Vec3::new(1.0, 0.0, 0.0);
+    let up = Vec3::new(0.0, 1.0, 0.0);
+    let forward = Vec3::new(0.0, 0.0, -1.0);
+
+    // The center of each face and its right and up directions, the side faces sharing the up
+    // direction and the up and down faces meeting the front face along an edge
+    let (center, face_right, face_up) = match (row, column) {
+        (0, 0) => (right, -forward, up),
+        (0, 1) => (-right, forward, up),
+        (0, _) => (up, right, -forward),
+        (_, 0) => (-up, right, forward),
+        (_, 1) => (forward, right, up),
+        (_, _) => (-forward, -right, up),
+    };
+
+    (center + a * face_right - b * face_up).unit_vector()
+}
+
+fn fisheye_direction(
+    mapping: FisheyeMapping,
+    fov: f32,
+    s: f32,
+    t: f32,
+    aspect_ratio: f32,
+) -> Option<Vec3> {
+    // Position relative to the image circle, which touches the shorter sides of the image
+    let x = (s - 0.5) * aspect_ratio;
+    let y = 0.5 - t;
+    let r = (x * x + y * y).sqrt() / (0.5 * aspect_ratio.min(1.0));
+    if r > 1.0 {
+        return None;
+    }
+
+    // Angle from the axis of the lens, reaching half the field of view at the edge of the circle
+    let theta_max = degree_to_radians(fov.clamp(0.0, 360.0) / 2.0);
+    let theta = match mapping {
+        FisheyeMapping::Equidistant => r * theta_max,
+        FisheyeMapping::Equisolid => 2.0 * (r * (theta_max / 2.0).sin()).asin(),
+        FisheyeMapping::Stereographic => 2.0 * (r * (theta_max.min(0.999 * PI) / 2.0).tan()).atan(),
+    };
+    let phi = y.atan2(x);
+
+    Some(Vec3::new(
+        theta.sin() * phi.cos(),
+        theta.sin() * phi.sin(),
+        -theta.cos(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Vec3, b: Vec3) {
+        assert!((a - b).length() < 1e-5, "{a} != {b}");
+    }
+
+    #[test]
+    fn equirectangular_centre_looks_forward() {
+        assert_close(
+            equirectangular_direction(0.5, 0.5),
+            Vec3::new(0.0, 0.0, -1.0),
+        );
+        assert_close(
+            equirectangular_direction(0.75, 0.5),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        assert_close(
+            equirectangular_direction(0.5, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn cube_map_faces_point_along_axes() {
+        assert_close(
+            cube_map_direction(0.5 / 3.0, 0.25),
+            Vec3::new(1.0, 0.0, 0.0),
+        );
+        assert_close(
+            cube_map_direction(1.5 / 3.0, 0.25),
+            Vec3::new(-1.0, 0.0, 0.0),
+        );
+        assert_close(
+            cube_map_direction(2.5 / 3.0, 0.25),
+            Vec3::new(0.0, 1.0, 0.0),
+        );
+        assert_close(
+            cube_map_direction(0.5 / 3.0, 0.75),
+            Vec3::new(0.0, -1.0, 0.0),
+        );
+        assert_close(
+            cube_map_direction(1.5 / 3.0, 0.75),
+            Vec3::new(0.0, 0.0, -1.0),
+        );
+        assert_close(
+            cube_map_direction(2.5 / 3.0, 0.75),
+            Vec3::new(0.0, 0.0, 1.0),
+        );
+
+        // The top edge of the front face meets the up face
+        let front_top = cube_map_direction(1.5 / 3.0, 0.5);
+        assert!(front_top.y() > 0.0 && front_top.z() < 0.0);
+    }
+
+    #[test]
+    fn fisheye_edge_reaches_half_the_field_of_view() {
+        for mapping in [
+            FisheyeMapping::Equidistant,
+            FisheyeMapping::Equisolid,
+            FisheyeMapping::Stereographic,
+        ] {
+            assert_close(
+                fisheye_direction(mapping, 180.0, 0.5, 0.5, 1.0).unwrap(),
+                Vec3::new(0.0, 0.0, -1.0),
+            );
+            assert_close(
+                fisheye_direction(mapping, 180.0, 1.0, 0.5, 1.0).unwrap(),
+                Vec3::new(1.0, 0.0, 0.0),
+            );
+            assert!(fisheye_direction(mapping, 180.0, 1.0, 0.0, 1.0).is_none());
+        }
+
+        assert_close(
+            fisheye_direction(FisheyeMapping::Equidistant, 360.0, 0.5, 0.0, 1.0).unwrap(),
+            Vec3::new(0.0, 0.0, 1.0),
+        );
+    }
+}
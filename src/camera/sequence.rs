@@ -0,0 +1,54 @@
+use std::ops::RangeInclusive;
+
+use crate::{animation::Track, vec3::Point3};
+
+use super::Camera;
+
+#[derive(Debug, Clone)]
+pub struct Sequence {
+    // Frame numbers to render, frame n showing the scene n / frames_per_second into the animation
+    pub frames: RangeInclusive<u32>,
+    pub frames_per_second: f32,
+
+    // Camera settings over time, left as they are on the camera when None
+    pub look_from: Option<Track<Point3>>,
+    pub look_at: Option<Track<Point3>>,
+    pub vfov: Option<Track<f32>>,
+    pub focus_dist: Option<Track<f32>>,
+}
+
+impl Sequence {
+    pub fn new(frames: RangeInclusive<u32>, frames_per_second: f32) -> Self {
+        Self {
+            frames,
+            frames_per_second,
+            look_from: None,
+            look_at: None,
+            vfov: None,
+            focus_dist: None,
+        }
+    }
+
+    pub fn time(&self, frame: u32) -> f32 {
+        frame as f32 / self.frames_per_second
+    }
+
+    pub fn filename(&self, frame: u32) -> String {
+        format!("frame_{frame:04}.png")
+    }
+
+    pub fn pose_camera(&self, camera: &mut Camera, time: f32) {
+        if let Some(track) = &self.look_from {
+            camera.look_from = track.at(time);
+        }
+        if let Some(track) = &self.look_at {
+            camera.look_at = track.at(time);
+        }
+        if let Some(track) = &self.vfov {
+            camera.vfov = track.at(time);
+        }
+        if let Some(track) = &self.focus_dist {
+            camera.focus_dist = track.at(time);
+        }
+    }
+}
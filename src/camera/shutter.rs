@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::{distribution::Distribution1D, utility::random_float};
+
+#[derive(Debug, Clone, Default)]
+pub enum ShutterCurve {
+    // Fully open from the moment the shutter opens until it closes
+    #[default]
+    Box,
+    // Opening and closing linearly, each over `ramp` of the exposure, up to a half for a
+    // triangle
+    Trapezoid {
+        ramp: f32,
+    },
+    // How far open the shutter is at even steps across the exposure
+    Tabulated(Arc<Distribution1D>),
+}
+
+impl ShutterCurve {
+    pub fn trapezoid(ramp: f32) -> ShutterCurve {
+        ShutterCurve::Trapezoid {
+            ramp: ramp.clamp(0.0, 0.5),
+        }
+    }
+
+    pub fn tabulated(openness: Vec<f32>) -> ShutterCurve {
+        ShutterCurve::Tabulated(Arc::new(Distribution1D::new(openness)))
+    }
+
+    pub fn sample(&self, u: f32) -> f32 {
+        // Maps u in [0, 1) to a point in the exposure in [0, 1), more often where the shutter is
+        // further open
+        match self {
+            ShutterCurve::Box => u,
+            ShutterCurve::Trapezoid { ramp } => {
+                let ramp = *ramp;
+                if ramp <= 0.0 {
+                    return u;
+                }
+
+                // Fully open height of a curve enclosing unit area, and the share of the
+                // exposure in each ramp
+                let height = 1.0 / (1.0 - ramp);
+                let ramp_area = 0.5 * height * ramp;
+
+                if u < ramp_area {
+                    (2.0 * u * ramp / height).sqrt()
+                } else if u > 1.0 - ramp_area {
+                    1.0 - (2.0 * (1.0 - u) * ramp / height).sqrt()
+                } else {
+                    ramp + (u - ramp_area) / height
+                }
+            }
+            ShutterCurve::Tabulated(distribution) => distribution.sample_continuous(u).0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Shutter {
+    // Scene times at which the first row of the image starts and stops being exposed
+    pub open: f32,
+    pub close: f32,
+    pub curve: ShutterCurve,
+    // Time taken to start exposing the last row after the first, zero for a global shutter
+    // exposing every row at once
+    pub readout: f32,
+}
+
+impl Default for Shutter {
+    fn default() -> Self {
+        Self::new(0.0, 1.0, ShutterCurve::Box)
+    }
+}
+
+impl Shutter {
+    pub fn new(open: f32, close: f32, curve: ShutterCurve) -> Self {
+        Self {
+            open,
+            close,
+            curve,
+            readout: 0.0,
+        }
+    }
+
+    pub fn rolling(self, readout: f32) -> Self {
+        Self { readout, ..self }
+    }
+
+    pub fn sample(&self, row: f32) -> f32 {
+        // Scene time of a ray for the image row at `row` in [0, 1] from the top
+        let start = self.open + row * self.readout;
+        start + (self.close - self.open) * self.curve.sample(random_float())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trapezoid_stays_in_exposure() {
+        let curve = ShutterCurve::trapezoid(0.25);
+
+        let samples: Vec<f32> = (0..=100).map(|i| curve.sample(i as f32 / 100.0)).collect();
+        assert!(samples.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(samples[0], 0.0);
+        assert!((samples[50] - 0.5).abs() < 1e-5);
+        assert!((samples[100] - 1.0).abs() < 1e-5);
+
+        // Less time is spent in the ramps than a box shutter would spend there
+        assert!(curve.sample(0.1) > 0.1);
+        assert!(curve.sample(0.9) < 0.9);
+    }
+
+    #[test]
+    fn rolling_shutter_delays_lower_rows() {
+        let shutter = Shutter::new(0.0, 0.0, ShutterCurve::Box).rolling(0.5);
+
+        assert_eq!(shutter.sample(0.0), 0.0);
+        assert_eq!(shutter.sample(1.0), 0.5);
+    }
+}
@@ -0,0 +1,77 @@
+#[derive(Debug, Clone, Copy)]
+pub enum Convergence {
+    // Both eyes look straight ahead, nothing has zero parallax
+    Parallel,
+    // Each eye turns inwards to look at the point at the focus distance
+    ToeIn,
+    // Both eyes share the image plane at the focus distance, without the vertical parallax of
+    // turning the eyes inwards
+    OffAxis,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum StereoLayout {
+    // Left eye on the left half of the image, right eye on the right half
+    SideBySide,
+    // Left eye on the top half of the image, right eye on the bottom half
+    OverUnder,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stereo {
+    // Distance between the eyes in world units
+    pub interpupillary_distance: f32,
+    pub convergence: Convergence,
+    pub layout: StereoLayout,
+}
+
+impl Stereo {
+    pub fn new(
+        interpupillary_distance: f32,
+        convergence: Convergence,
+        layout: StereoLayout,
+    ) -> Self {
+        Self {
+            interpupillary_distance,
+            convergence,
+            layout,
+        }
+    }
+
+    pub fn image_size(&self, eye_width: u32, eye_height: u32) -> (u32, u32) {
+        // Size of the image holding both eyes
+        match self.layout {
+            StereoLayout::SideBySide => (2 * eye_width, eye_height),
+            StereoLayout::OverUnder => (eye_width, 2 * eye_height),
+        }
+    }
+
+    pub fn eye_pixel(&self, x: u32, y: u32, eye_width: u32, eye_height: u32) -> (f32, u32, u32) {
+        // The eye seeing pixel x, y of the combined image, as its offset from the camera center to
+        // the right, and the pixel in that eye's view
+        let half = self.interpupillary_distance / 2.0;
+        let (left, i, j) = match self.layout {
+            StereoLayout::SideBySide => (x < eye_width, x % eye_width, y),
+            StereoLayout::OverUnder => (y < eye_height, x, y % eye_height),
+        };
+
+        (if left { -half } else { half }, i, j)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn left_eye_comes_first() {
+        let side_by_side = Stereo::new(0.064, Convergence::Parallel, StereoLayout::SideBySide);
+        assert_eq!(side_by_side.image_size(100, 50), (200, 50));
+        assert_eq!(side_by_side.eye_pixel(10, 20, 100, 50), (-0.032, 10, 20));
+        assert_eq!(side_by_side.eye_pixel(110, 20, 100, 50), (0.032, 10, 20));
+
+        let over_under = Stereo::new(0.064, Convergence::Parallel, StereoLayout::OverUnder);
+        assert_eq!(over_under.image_size(100, 50), (100, 100));
+        assert_eq!(over_under.eye_pixel(10, 70, 100, 50), (0.032, 10, 20));
+    }
+}
@@ -17,7 +17,19 @@ impl Color {
         }
     }
 
+    pub fn luminance(&self) -> f32 {
+        // Relative luminance of a linear Rec. 709 color
+        0.2126 * self.x() + 0.7152 * self.y() + 0.0722 * self.z()
+    }
+
     pub fn to_color(&self) -> String {
+        let [r, g, b] = self.to_bytes();
+
+        // Write out pixel
+        format!("{r} {g} {b}\n")
+    }
+
+    pub fn to_bytes(self) -> [u8; 3] {
         let r = self.x();
         let g = self.y();
         let b = self.z();
@@ -32,7 +44,6 @@ impl Color {
         let g = (INTENSITY.clamp(g) * 256.0) as u8;
         let b = (INTENSITY.clamp(b) * 256.0) as u8;
 
-        // Write out pixel
-        format!("{r} {g} {b}\n")
+        [r, g, b]
     }
 }
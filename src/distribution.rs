@@ -0,0 +1,124 @@
+use crate::utility::random_float;
+
+#[derive(Debug, Clone)]
+pub struct Distribution1D {
+    func: Vec<f32>,
+    cdf: Vec<f32>,
+    func_int: f32,
+}
+
+impl Distribution1D {
+    pub fn new(func: Vec<f32>) -> Distribution1D {
+        // Piecewise constant distribution over [0, 1) proportional to the function values
+        let n = func.len();
+        let mut cdf = vec![0.0; n + 1];
+        for i in 1..=n {
+            cdf[i] = cdf[i - 1] + func[i - 1].abs() / n as f32;
+        }
+
+        let func_int = cdf[n];
+        for (i, c) in cdf.iter_mut().enumerate().skip(1) {
+            *c = if func_int == 0.0 {
+                i as f32 / n as f32
+            } else {
+                *c / func_int
+            };
+        }
+
+        Distribution1D {
+            func,
+            cdf,
+            func_int,
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.func.len()
+    }
+
+    pub fn integral(&self) -> f32 {
+        self.func_int
+    }
+
+    pub fn sample_continuous(&self, u: f32) -> (f32, f32, usize) {
+        // Returns the sampled position in [0, 1), its density and the index of its segment
+        let offset = self.cdf.partition_point(|c| *c <= u).clamp(1, self.count()) - 1;
+
+        let mut du = u - self.cdf[offset];
+        let width = self.cdf[offset + 1] - self.cdf[offset];
+        if width > 0.0 {
+            du /= width;
+        }
+
+        let pdf = if self.func_int > 0.0 {
+            self.func[offset] / self.func_int
+        } else {
+            0.0
+        };
+
+        ((offset as f32 + du) / self.count() as f32, pdf, offset)
+    }
+
+    pub fn pdf(&self, x: f32) -> f32 {
+        if self.func_int <= 0.0 {
+            return 0.0;
+        }
+
+        let offset = ((x * self.count() as f32) as usize).min(self.count() - 1);
+        self.func[offset] / self.func_int
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Distribution2D {
+    // One distribution over u for every row, and the distribution of picking each row
+    conditional: Vec<Distribution1D>,
+    marginal: Distribution1D,
+}
+
+impl Distribution2D {
+    pub fn new(func: &[f32], width: usize, height: usize) -> Distribution2D {
+        // `func` holds `height` rows of `width` values each
+        let conditional: Vec<_> = func
+            .chunks(width)
+            .take(height)
+            .map(|row| Distribution1D::new(row.to_vec()))
+            .collect();
+        let marginal = Distribution1D::new(conditional.iter().map(|c| c.integral()).collect());
+
+        Distribution2D {
+            conditional,
+            marginal,
+        }
+    }
+
+    pub fn sample(&self) -> (f32, f32, f32) {
+        // Returns a random (u, v) position in [0, 1)^2 and its density
+        let (v, pdf_v, row) = self.marginal.sample_continuous(random_float());
+        let (u, pdf_u, _) = self.conditional[row].sample_continuous(random_float());
+
+        (u, v, pdf_u * pdf_v)
+    }
+
+    pub fn pdf(&self, u: f32, v: f32) -> f32 {
+        let marginal_count = self.marginal.count();
+        let row = ((v * marginal_count as f32) as usize).min(marginal_count - 1);
+        self.conditional[row].pdf(u) * self.marginal.pdf(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_follows_function() {
+        let distribution = Distribution1D::new(vec![0.0, 1.0, 3.0, 0.0]);
+
+        let (x, pdf, offset) = distribution.sample_continuous(0.5);
+        assert_eq!(offset, 2);
+        assert!((0.5..0.75).contains(&x));
+        assert_eq!(pdf, 3.0 / distribution.integral());
+        assert_eq!(distribution.pdf(0.1), 0.0);
+    }
+}
@@ -1,31 +1,54 @@
 use std::sync::Arc;
 
+use image::DynamicImage;
+
 use crate::{
     aabb::Aabb,
+    animation::Track,
     color::Color,
     interval::Interval,
+    mat4::Mat4,
     material::MaterialType,
     ray::Ray,
     texture::TextureType,
     vec3::{Point3, Vec3},
 };
 
+pub mod animated;
 pub mod bvh_node;
+pub mod cone;
 pub mod constant_medium;
+pub mod csg;
+pub mod cylinder;
+pub mod disk;
+pub mod heightfield;
 pub mod hittable_list;
+pub mod instance;
+pub mod plane;
 pub mod quad;
-pub mod rotate_y;
+pub mod sdf;
 pub mod sphere;
-pub mod translate;
+pub mod torus;
+pub mod transform;
 
+use animated::{Animated, Pose};
 use bvh_node::BvhNode;
+use cone::Cone;
 use constant_medium::ConstantMedium;
+use csg::{Csg, CsgOperation};
+use cylinder::Cylinder;
+use disk::Disk;
+use heightfield::Heightfield;
 use hittable_list::HittableList;
+use instance::Instance;
+use plane::Plane;
 use quad::Quad;
-use rotate_y::RotateY;
+use sdf::{field::DistanceField, Sdf};
 use sphere::Sphere;
-use translate::Translate;
+use torus::Torus;
+use transform::Transform;
 
+#[derive(Clone)]
 pub struct HitRecord {
     pub p: Point3,
     pub normal: Vec3,
@@ -41,10 +64,19 @@ pub enum HittableObject {
     Sphere(Sphere),
     BvhNode(BvhNode),
     Quad(Quad),
+    Plane(Plane),
+    Disk(Disk),
+    Cylinder(Cylinder),
+    Cone(Cone),
+    Torus(Torus),
+    Csg(Csg),
+    Sdf(Sdf),
+    Heightfield(Heightfield),
     HittableList(HittableList),
-    Translate(Translate),
-    RotateY(RotateY),
+    Transform(Transform),
+    Instance(Instance),
     ConstantMedium(ConstantMedium),
+    Animated(Animated),
 }
 
 impl HittableObject {
@@ -53,10 +85,19 @@ impl HittableObject {
             HittableObject::Sphere(sphere) => sphere.hit(ray, ray_t, hit_record),
             HittableObject::BvhNode(bvh_node) => bvh_node.hit(ray, ray_t, hit_record),
             HittableObject::Quad(quad) => quad.hit(ray, ray_t, hit_record),
+            HittableObject::Plane(plane) => plane.hit(ray, ray_t, hit_record),
+            HittableObject::Disk(disk) => disk.hit(ray, ray_t, hit_record),
+            HittableObject::Cylinder(cylinder) => cylinder.hit(ray, ray_t, hit_record),
+            HittableObject::Cone(cone) => cone.hit(ray, ray_t, hit_record),
+            HittableObject::Torus(torus) => torus.hit(ray, ray_t, hit_record),
+            HittableObject::Csg(csg) => csg.hit(ray, ray_t, hit_record),
+            HittableObject::Sdf(sdf) => sdf.hit(ray, ray_t, hit_record),
+            HittableObject::Heightfield(heightfield) => heightfield.hit(ray, ray_t, hit_record),
             HittableObject::HittableList(list) => list.hit(ray, ray_t, hit_record),
-            HittableObject::Translate(translate) => translate.hit(ray, ray_t, hit_record),
-            HittableObject::RotateY(rot_y) => rot_y.hit(ray, ray_t, hit_record),
+            HittableObject::Transform(transform) => transform.hit(ray, ray_t, hit_record),
+            HittableObject::Instance(instance) => instance.hit(ray, ray_t, hit_record),
             HittableObject::ConstantMedium(cm) => cm.hit(ray, ray_t, hit_record),
+            HittableObject::Animated(animated) => animated.hit(ray, ray_t, hit_record),
         }
     }
 
@@ -65,10 +106,94 @@ impl HittableObject {
             HittableObject::Sphere(sphere) => sphere.bounding_box(),
             HittableObject::BvhNode(bvh_node) => bvh_node.bounding_box(),
             HittableObject::Quad(quad) => quad.bounding_box(),
+            HittableObject::Plane(plane) => plane.bounding_box(),
+            HittableObject::Disk(disk) => disk.bounding_box(),
+            HittableObject::Cylinder(cylinder) => cylinder.bounding_box(),
+            HittableObject::Cone(cone) => cone.bounding_box(),
+            HittableObject::Torus(torus) => torus.bounding_box(),
+            HittableObject::Csg(csg) => csg.bounding_box(),
+            HittableObject::Sdf(sdf) => sdf.bounding_box(),
+            HittableObject::Heightfield(heightfield) => heightfield.bounding_box(),
             HittableObject::HittableList(list) => list.bounding_box(),
-            HittableObject::Translate(translate) => translate.bounding_box(),
-            HittableObject::RotateY(rot_y) => rot_y.bounding_box(),
+            HittableObject::Transform(transform) => transform.bounding_box(),
+            HittableObject::Instance(instance) => instance.bounding_box(),
             HittableObject::ConstantMedium(cm) => cm.bounding_box(),
+            HittableObject::Animated(animated) => animated.bounding_box(),
+        }
+    }
+
+    pub fn pdf_value(&self, origin: &Point3, direction: &Vec3) -> f32 {
+        match self {
+            HittableObject::Sphere(sphere) => sphere.pdf_value(origin, direction),
+            HittableObject::BvhNode(bvh_node) => bvh_node.pdf_value(origin, direction),
+            HittableObject::Quad(quad) => quad.pdf_value(origin, direction),
+            HittableObject::Plane(plane) => plane.pdf_value(origin, direction),
+            HittableObject::Disk(disk) => disk.pdf_value(origin, direction),
+            HittableObject::Cylinder(cylinder) => cylinder.pdf_value(origin, direction),
+            HittableObject::Cone(cone) => cone.pdf_value(origin, direction),
+            HittableObject::Torus(torus) => torus.pdf_value(origin, direction),
+            HittableObject::Csg(csg) => csg.pdf_value(origin, direction),
+            HittableObject::Sdf(sdf) => sdf.pdf_value(origin, direction),
+            HittableObject::Heightfield(heightfield) => heightfield.pdf_value(origin, direction),
+            HittableObject::HittableList(list) => list.pdf_value(origin, direction),
+            HittableObject::Transform(transform) => transform.pdf_value(origin, direction),
+            HittableObject::Instance(instance) => instance.pdf_value(origin, direction),
+            HittableObject::ConstantMedium(cm) => cm.pdf_value(origin, direction),
+            HittableObject::Animated(animated) => animated.pdf_value(origin, direction),
+        }
+    }
+
+    pub fn random(&self, origin: &Point3) -> Vec3 {
+        match self {
+            HittableObject::Sphere(sphere) => sphere.random(origin),
+            HittableObject::BvhNode(bvh_node) => bvh_node.random(origin),
+            HittableObject::Quad(quad) => quad.random(origin),
+            HittableObject::Plane(plane) => plane.random(origin),
+            HittableObject::Disk(disk) => disk.random(origin),
+            HittableObject::Cylinder(cylinder) => cylinder.random(origin),
+            HittableObject::Cone(cone) => cone.random(origin),
+            HittableObject::Torus(torus) => torus.random(origin),
+            HittableObject::Csg(csg) => csg.random(origin),
+            HittableObject::Sdf(sdf) => sdf.random(origin),
+            HittableObject::Heightfield(heightfield) => heightfield.random(origin),
+            HittableObject::HittableList(list) => list.random(origin),
+            HittableObject::Transform(transform) => transform.random(origin),
+            HittableObject::Instance(instance) => instance.random(origin),
+            HittableObject::ConstantMedium(cm) => cm.random(origin),
+            HittableObject::Animated(animated) => animated.random(origin),
+        }
+    }
+
+    pub fn intervals(&self, ray: &Ray) -> Vec<Span> {
+        // Stretches of the line of the ray inside a closed object, for combining solids. Open
+        // surfaces enclose nothing, so they are left out of combinations
+        match self {
+            HittableObject::Sphere(sphere) => sphere.intervals(ray),
+            HittableObject::BvhNode(bvh_node) => bvh_node.intervals(ray),
+            HittableObject::Plane(plane) => plane.intervals(ray),
+            HittableObject::Cylinder(cylinder) if cylinder.is_capped() => cylinder.intervals(ray),
+            HittableObject::Cone(cone) => cone.intervals(ray),
+            HittableObject::Torus(torus) => torus.intervals(ray),
+            HittableObject::Csg(csg) => csg.intervals(ray),
+            HittableObject::Sdf(sdf) => sdf.intervals(ray),
+            HittableObject::Quad(_)
+            | HittableObject::Disk(_)
+            | HittableObject::Cylinder(_)
+            | HittableObject::Heightfield(_) => vec![],
+            HittableObject::HittableList(list) => list.intervals(ray),
+            HittableObject::Transform(transform) => transform.intervals(ray),
+            HittableObject::Instance(instance) => instance.intervals(ray),
+            HittableObject::ConstantMedium(cm) => cm.intervals(ray),
+            HittableObject::Animated(animated) => animated.intervals(ray),
+        }
+    }
+
+    pub fn material(&self) -> Option<&Arc<MaterialType>> {
+        // The material of a single surface, used to recognise emitters that are also lights
+        match self {
+            HittableObject::Sphere(sphere) => Some(sphere.material()),
+            HittableObject::Quad(quad) => Some(quad.material()),
+            _ => None,
         }
     }
 
@@ -76,12 +201,36 @@ impl HittableObject {
         HittableObject::Sphere(Sphere::new_stationary(center, radius, mat))
     }
 
+    pub fn transform(object: HittableObject, matrix: Mat4) -> HittableObject {
+        HittableObject::Transform(Transform::new(object, matrix))
+    }
+
+    pub fn instance(
+        geometry: &Arc<HittableObject>,
+        matrix: Mat4,
+        material: Option<Arc<MaterialType>>,
+    ) -> HittableObject {
+        HittableObject::Instance(Instance::new(geometry, matrix, material))
+    }
+
     pub fn translate(object: HittableObject, offset: Vec3) -> HittableObject {
-        HittableObject::Translate(Translate::new(object, offset))
+        HittableObject::transform(object, Mat4::translation(offset))
+    }
+
+    pub fn rotate(object: HittableObject, axis: Vec3, angle: f32) -> HittableObject {
+        HittableObject::transform(object, Mat4::rotation(axis, angle))
     }
 
     pub fn rotate_y(object: HittableObject, angle: f32) -> HittableObject {
-        HittableObject::RotateY(RotateY::new(object, angle))
+        HittableObject::rotate(object, Vec3::new(0.0, 1.0, 0.0), angle)
+    }
+
+    pub fn scale(object: HittableObject, factors: Vec3) -> HittableObject {
+        HittableObject::transform(object, Mat4::scaling(factors))
+    }
+
+    pub fn animated(object: HittableObject, poses: Track<Pose>) -> HittableObject {
+        HittableObject::Animated(Animated::new(object, poses))
     }
 
     pub fn new_box(a: Point3, b: Point3, mat: MaterialType) -> HittableObject {
@@ -164,6 +313,81 @@ impl HittableObject {
         HittableObject::Quad(Quad::new(q, u, v, mat))
     }
 
+    pub fn triangle(q: Point3, u: Vec3, v: Vec3, mat: MaterialType) -> HittableObject {
+        HittableObject::Quad(Quad::triangle(q, u, v, mat))
+    }
+
+    pub fn ellipse(center: Point3, u: Vec3, v: Vec3, mat: MaterialType) -> HittableObject {
+        HittableObject::Quad(Quad::ellipse(center, u, v, mat))
+    }
+
+    pub fn annulus(
+        center: Point3,
+        u: Vec3,
+        v: Vec3,
+        inner: f32,
+        mat: MaterialType,
+    ) -> HittableObject {
+        HittableObject::Quad(Quad::annulus(center, u, v, inner, mat))
+    }
+
+    pub fn plane(point: Point3, normal: Vec3, tile_size: f32, mat: MaterialType) -> HittableObject {
+        HittableObject::Plane(Plane::new(point, normal, tile_size, mat))
+    }
+
+    pub fn disk(center: Point3, normal: Vec3, radius: f32, mat: MaterialType) -> HittableObject {
+        HittableObject::Disk(Disk::new(center, normal, radius, mat))
+    }
+
+    pub fn cylinder(
+        base: Point3,
+        top: Point3,
+        radius: f32,
+        capped: bool,
+        mat: MaterialType,
+    ) -> HittableObject {
+        HittableObject::Cylinder(Cylinder::new(base, top, radius, capped, mat))
+    }
+
+    pub fn cone(base: Point3, apex: Point3, radius: f32, mat: MaterialType) -> HittableObject {
+        HittableObject::Cone(Cone::new(base, apex, radius, mat))
+    }
+
+    pub fn torus(
+        center: Point3,
+        axis: Vec3,
+        major_radius: f32,
+        minor_radius: f32,
+        mat: MaterialType,
+    ) -> HittableObject {
+        HittableObject::Torus(Torus::new(center, axis, major_radius, minor_radius, mat))
+    }
+
+    pub fn union(left: HittableObject, right: HittableObject) -> HittableObject {
+        HittableObject::Csg(Csg::new(left, right, CsgOperation::Union))
+    }
+
+    pub fn intersection(left: HittableObject, right: HittableObject) -> HittableObject {
+        HittableObject::Csg(Csg::new(left, right, CsgOperation::Intersection))
+    }
+
+    pub fn difference(left: HittableObject, right: HittableObject) -> HittableObject {
+        HittableObject::Csg(Csg::new(left, right, CsgOperation::Difference))
+    }
+
+    pub fn sdf(field: DistanceField, bbox: Aabb, mat: MaterialType) -> HittableObject {
+        HittableObject::Sdf(Sdf::new(field, bbox, mat))
+    }
+
+    pub fn heightfield(
+        image: &DynamicImage,
+        corner: Point3,
+        size: Vec3,
+        mat: MaterialType,
+    ) -> HittableObject {
+        HittableObject::Heightfield(Heightfield::new(image, corner, size, mat))
+    }
+
     pub fn constant_medium_tex(
         object: HittableObject,
         neg_inv_density: f32,
@@ -181,7 +405,22 @@ impl HittableObject {
     }
 }
 
+pub struct Span {
+    // Where the ray goes into a solid and comes back out, at minus or plus infinity when the line
+    // of the ray starts or ends inside it
+    pub enter: HitRecord,
+    pub exit: HitRecord,
+}
+
 impl HitRecord {
+    pub fn at_infinity(t: f32) -> HitRecord {
+        // Stand-in for a crossing that never happens, at one end of the line of the ray
+        HitRecord {
+            t,
+            ..Default::default()
+        }
+    }
+
     pub fn set_face_normal(&mut self, ray: &Ray, outward_normal: &Vec3) {
         // Sets the hit record normal vector
         // NOTE: the parameter `outward_normal` is assumed to have unit length
@@ -214,4 +453,56 @@ pub trait Hittable: Sync + Send {
     }
 
     fn bounding_box(&self) -> &Aabb;
+
+    fn pdf_value(&self, _origin: &Point3, _direction: &Vec3) -> f32 {
+        // Density over solid angle of `random` picking the direction from the origin
+        0.0
+    }
+
+    fn random(&self, _origin: &Point3) -> Vec3 {
+        // Random direction from the origin towards the object
+        Vec3::new(1.0, 0.0, 0.0)
+    }
+
+    fn intervals(&self, ray: &Ray) -> Vec<Span> {
+        // Stretches of the whole line of the ray inside the object, walking through its surface
+        // one crossing at a time. Only meaningful for closed surfaces, whose front faces are where
+        // the ray goes in, open ones like quads and disks give spans that don't bound anything.
+        let mut spans = vec![];
+        let mut enter = HitRecord::at_infinity(f32::NEG_INFINITY);
+        let mut depth = 0;
+        let mut t_min = f32::NEG_INFINITY;
+
+        loop {
+            let mut rec = HitRecord::default();
+            if !self.hit(ray, &Interval::new(t_min, f32::INFINITY), &mut rec) {
+                break;
+            }
+            t_min = rec.t + 0.001;
+
+            // Overlapping parts, like spheres in a list, nest rather than end each other
+            if rec.front_face {
+                if depth == 0 {
+                    enter = rec;
+                }
+                depth += 1;
+            } else if depth <= 1 {
+                let enter =
+                    std::mem::replace(&mut enter, HitRecord::at_infinity(f32::NEG_INFINITY));
+                spans.push(Span { enter, exit: rec });
+                depth = 0;
+            } else {
+                depth -= 1;
+            }
+        }
+
+        if depth > 0 {
+            spans.push(Span {
+                enter,
+                exit: HitRecord::at_infinity(f32::INFINITY),
+            });
+        }
+
+        spans
+    }
 }
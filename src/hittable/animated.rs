@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use crate::{
+    aabb::Aabb,
+    animation::{Lerp, Track},
+    interval::Interval,
+    mat4::Mat4,
+    ray::Ray,
+    vec3::{Point3, Vec3},
+};
+
+use super::{HitRecord, Hittable, HittableObject};
+
+// Times sampled between each pair of keyframes to bound the motion
+const BOUND_STEPS: usize = 16;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Pose {
+    pub translation: Vec3,
+    // Angles in degrees about the x, y and z axes, applied in that order
+    pub rotation: Vec3,
+    pub scale: Vec3,
+}
+
+impl Default for Pose {
+    fn default() -> Self {
+        Self {
+            translation: Vec3::default(),
+            rotation: Vec3::default(),
+            scale: Vec3::new(1.0, 1.0, 1.0),
+        }
+    }
+}
+
+impl Pose {
+    pub fn new(translation: Vec3, rotation: Vec3, scale: Vec3) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    fn matrices(&self) -> (Mat4, Mat4) {
+        // From object to world space scaling, then rotating about x, y and z in turn, then
+        // translating, and the inverse undoing those in reverse
+        let (x, y, z) = (
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        );
+        let inv_scale = Vec3::new(
+            1.0 / self.scale.x(),
+            1.0 / self.scale.y(),
+            1.0 / self.scale.z(),
+        );
+
+        let matrix = Mat4::translation(self.translation)
+            * Mat4::rotation(z, self.rotation.z())
+            * Mat4::rotation(y, self.rotation.y())
+            * Mat4::rotation(x, self.rotation.x())
+            * Mat4::scaling(self.scale);
+        let inverse = Mat4::scaling(inv_scale)
+            * Mat4::rotation(x, -self.rotation.x())
+            * Mat4::rotation(y, -self.rotation.y())
+            * Mat4::rotation(z, -self.rotation.z())
+            * Mat4::translation(-self.translation);
+
+        (matrix, inverse)
+    }
+}
+
+impl Lerp for Pose {
+    fn lerp(&self, other: &Self, f: f32) -> Self {
+        Self {
+            translation: self.translation.lerp(&other.translation, f),
+            rotation: self.rotation.lerp(&other.rotation, f),
+            scale: self.scale.lerp(&other.scale, f),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Animated {
+    object: Arc<HittableObject>,
+    // Pose of the object over time, holding the first and last poses outside the keyframes
+    poses: Track<Pose>,
+    bbox: Aabb,
+}
+
+impl Animated {
+    pub fn new(object: HittableObject, poses: Track<Pose>) -> Self {
+        let keys = poses.keys();
+        let span = Interval::new(keys[0].0, keys[keys.len() - 1].0);
+        let bbox = Self::motion_bounds(object.bounding_box(), &poses, &span);
+
+        Self {
+            object: Arc::new(object),
+            poses,
+            bbox,
+        }
+    }
+
+    pub fn during(&self, span: &Interval) -> Self {
+        // The same object bounded only over the motion within `span`, for rays sent at those
+        // times
+        Self {
+            object: self.object.clone(),
+            poses: self.poses.clone(),
+            bbox: Self::motion_bounds(self.object.bounding_box(), &self.poses, span),
+        }
+    }
+
+    fn motion_bounds(bbox: &Aabb, poses: &Track<Pose>, span: &Interval) -> Aabb {
+        // Bound the corners of the object's box at steps through the motion. A corner moving
+        // along a curve stays within one step of where it was seen, so the box is grown by the
+        // longest step any corner takes.
+        let mut stops = vec![span.min];
+        stops.extend(
+            poses
+                .keys()
+                .iter()
+                .map(|(time, _)| *time)
+                .filter(|time| span.surrounds(*time)),
+        );
+        stops.push(span.max);
+
+        let mut times = vec![span.min];
+        for pair in stops.windows(2) {
+            let (t0, t1) = (pair[0], pair[1]);
+            times.extend((1..=BOUND_STEPS).map(|i| t0 + (t1 - t0) * i as f32 / BOUND_STEPS as f32));
+        }
+
+        let corners: Vec<Point3> = (0..8)
+            .map(|i| {
+                Point3::new(
+                    if i & 1 == 0 { bbox.x.min } else { bbox.x.max },
+                    if i & 2 == 0 { bbox.y.min } else { bbox.y.max },
+                    if i & 4 == 0 { bbox.z.min } else { bbox.z.max },
+                )
+            })
+            .collect();
+
+        let mut min = Point3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+        let mut max = Point3::new(-f32::INFINITY, -f32::INFINITY, -f32::INFINITY);
+        let mut longest_step = 0.0_f32;
+        let mut previous: Option<Vec<Point3>> = None;
+
+        for time in times {
+            let (matrix, _) = poses.at(time).matrices();
+            let moved: Vec<Point3> = corners.iter().map(|c| matrix.transform_point(c)).collect();
+
+            for p in &moved {
+                for c in 0..3 {
+                    min[c] = min[c].min(p[c]);
+                    max[c] = max[c].max(p[c]);
+                }
+            }
+            if let Some(previous) = &previous {
+                for (a, b) in previous.iter().zip(&moved) {
+                    longest_step = longest_step.max((*b - *a).length());
+                }
+            }
+            previous = Some(moved);
+        }
+
+        let bounds = Aabb::from_points(&min, &max);
+        Aabb::new(
+            bounds.x.expands(2.0 * longest_step),
+            bounds.y.expands(2.0 * longest_step),
+            bounds.z.expands(2.0 * longest_step),
+        )
+    }
+}
+
+impl Hittable for Animated {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        let (matrix, inverse) = self.poses.at(ray.time()).matrices();
+
+        // Transform the ray into the object's space at the time it was sent. The direction is
+        // not normalized so distances along the ray stay the same in both spaces.
+        let object_r = Ray::new(
+            &inverse.transform_point(ray.origin()),
+            &inverse.transform_vector(ray.direction()),
+            ray.time(),
+        );
+
+        if !self.object.hit(&object_r, ray_t, hit_record) {
+            return false;
+        }
+
+        hit_record.p = matrix.transform_point(&hit_record.p);
+        hit_record.normal = inverse.transform_normal(&hit_record.normal).unit_vector();
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{animation::Interpolation, material::MaterialType};
+
+    use super::*;
+
+    #[test]
+    fn bounds_cover_the_whole_motion() {
+        // A unit cube half a turn around the y axis and across to x = 4 over the keyframes
+        let cube = HittableObject::new_box(
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(2.0, 1.0, 1.0),
+            MaterialType::None,
+        );
+        let poses = Track::new(
+            vec![
+                (0.0, Pose::default()),
+                (
+                    1.0,
+                    Pose::new(
+                        Vec3::new(4.0, 0.0, 0.0),
+                        Vec3::new(0.0, 180.0, 0.0),
+                        Vec3::new(1.0, 1.0, 1.0),
+                    ),
+                ),
+            ],
+            Interpolation::Linear,
+        );
+        let animated = Animated::new(cube, poses.clone());
+        let bbox = animated.bounding_box();
+
+        for i in 0..=100 {
+            let (matrix, _) = poses.at(i as f32 / 100.0).matrices();
+            for corner in [Point3::new(1.0, 0.0, 0.0), Point3::new(2.0, 1.0, 1.0)] {
+                let p = matrix.transform_point(&corner);
+                assert!(bbox.x.contains(p.x()) && bbox.y.contains(p.y()));
+                assert!(bbox.z.contains(p.z()));
+            }
+        }
+    }
+
+    #[test]
+    fn hits_follow_the_pose_at_the_ray_time() {
+        let ball = HittableObject::stationary_sphere(Point3::default(), 1.0, MaterialType::None);
+        let poses = Track::new(
+            vec![
+                (0.0, Pose::default()),
+                (
+                    1.0,
+                    Pose::new(
+                        Vec3::new(0.0, 0.0, 0.0),
+                        Vec3::default(),
+                        Vec3::new(2.0, 1.0, 1.0),
+                    ),
+                ),
+            ],
+            Interpolation::Linear,
+        );
+        let animated = Animated::new(ball, poses);
+
+        // Stretched to twice its width by the end, the ball is hit a unit further out
+        let ray = Ray::new(&Point3::new(5.0, 0.0, 0.0), &Vec3::new(-1.0, 0.0, 0.0), 1.0);
+        let mut rec = HitRecord::default();
+        assert!(animated.hit(&ray, &Interval::new(0.001, f32::INFINITY), &mut rec));
+        assert!((rec.p.x() - 2.0).abs() < 1e-4);
+        assert!((rec.normal.x() - 1.0).abs() < 1e-4);
+    }
+}
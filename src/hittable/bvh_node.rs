@@ -13,6 +13,23 @@ pub struct BvhNode {
 
 impl BvhNode {
     pub fn new(objects: &mut [HittableObject]) -> Self {
+        // Unbounded objects would stretch the boxes all the way up the tree and leave nothing to
+        // split on, so they hang off the root beside a hierarchy of everything else
+        let unbounded = objects
+            .iter()
+            .filter(|object| !object.bounding_box().is_bounded())
+            .count();
+        if unbounded > 0 && unbounded < objects.len() {
+            objects.sort_by_key(|object| object.bounding_box().is_bounded());
+            let (unbounded, bounded) = objects.split_at_mut(unbounded);
+
+            return Self {
+                left: Arc::new(HittableObject::bvh_node(unbounded)),
+                right: Arc::new(HittableObject::bvh_node(bounded)),
+                bbox: Aabb::UNIVERSE,
+            };
+        }
+
         let mut bbox = Aabb::EMPTY;
         for object in objects.iter() {
             bbox = Aabb::from_aabbs(&bbox, object.bounding_box());
@@ -98,3 +115,46 @@ impl Hittable for BvhNode {
         hit_left || hit_right
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        hittable::HitRecord,
+        material::MaterialType,
+        ray::Ray,
+        vec3::{Point3, Vec3},
+    };
+
+    use super::*;
+
+    #[test]
+    fn keeps_planes_beside_bounded_objects() {
+        let mut objects = vec![
+            HittableObject::stationary_sphere(Point3::new(0.0, 1.0, 0.0), 1.0, MaterialType::None),
+            HittableObject::plane(
+                Point3::default(),
+                Vec3::new(0.0, 1.0, 0.0),
+                1.0,
+                MaterialType::None,
+            ),
+            HittableObject::stationary_sphere(Point3::new(5.0, 1.0, 0.0), 1.0, MaterialType::None),
+        ];
+        let bvh = BvhNode::new(&mut objects);
+        let t_range = Interval::new(0.001, f32::INFINITY);
+
+        // Far from both spheres, only the plane is there to hit
+        let ray = Ray::new(
+            &Point3::new(-500.0, 3.0, 40.0),
+            &Vec3::new(0.0, -1.0, 0.0),
+            0.0,
+        );
+        let mut rec = HitRecord::default();
+        assert!(bvh.hit(&ray, &t_range, &mut rec));
+        assert!((rec.t - 3.0).abs() < 1e-5);
+
+        // The sphere in front of the plane is still found first
+        let ray = Ray::new(&Point3::new(5.0, 5.0, 0.0), &Vec3::new(0.0, -1.0, 0.0), 0.0);
+        assert!(bvh.hit(&ray, &t_range, &mut rec));
+        assert!((rec.t - 3.0).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use crate::{
+    aabb::Aabb,
+    interval::Interval,
+    material::MaterialType,
+    onb::Onb,
+    ray::Ray,
+    utility::turn_fraction,
+    vec3::{Point3, Vec3},
+};
+
+use super::{HitRecord, Hittable};
+
+#[derive(Clone)]
+pub struct Cone {
+    // Center of the base disk, with the axis running up w of the frame to the apex
+    base: Point3,
+    frame: Onb,
+    radius: f32,
+    height: f32,
+    mat: Arc<MaterialType>,
+    bbox: Aabb,
+}
+
+impl Cone {
+    pub fn new(base: Point3, apex: Point3, radius: f32, mat: MaterialType) -> Self {
+        let radius = radius.max(0.0);
+        let axis = apex - base;
+
+        let bbox = Aabb::from_aabbs(
+            &Aabb::from_circle(&base, &axis, radius),
+            &Aabb::from_points(&apex, &apex),
+        );
+
+        Self {
+            base,
+            frame: Onb::new(&axis),
+            radius,
+            height: axis.length(),
+            mat: Arc::new(mat),
+            bbox,
+        }
+    }
+}
+
+impl Hittable for Cone {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        // Intersect in the frame of the cone, where the axis is z from the base
+        let o = self.frame.untransform(&(*ray.origin() - self.base));
+        let d = self.frame.untransform(ray.direction());
+
+        let mut closest = ray_t.max;
+        let mut found = None;
+
+        // The side, where x^2 + y^2 = (k (h - z))^2 with the radius shrinking by k per unit up
+        let k2 = (self.radius / self.height).powi(2);
+        let rise = self.height - o.z();
+        let a = d.x() * d.x() + d.y() * d.y() - k2 * d.z() * d.z();
+        let half_b = o.x() * d.x() + o.y() * d.y() + k2 * rise * d.z();
+        let c = o.x() * o.x() + o.y() * o.y() - k2 * rise * rise;
+
+        let roots = if a.abs() < 1e-8 {
+            // Parallel to the side, crossing it at most once
+            [-c / (2.0 * half_b), f32::NAN]
+        } else {
+            let discriminant = half_b * half_b - a * c;
+            let sqrtd = discriminant.sqrt();
+            let (t0, t1) = ((-half_b - sqrtd) / a, (-half_b + sqrtd) / a);
+            [t0.min(t1), t0.max(t1)]
+        };
+
+        // Roots that do not exist are NaN, and never in the interval
+        for root in roots {
+            let p = o + root * d;
+            if Interval::new(ray_t.min, closest).surrounds(root)
+                && (0.0..=self.height).contains(&p.z())
+            {
+                let u = turn_fraction(p.x(), p.y());
+
+                // The gradient of the surface, straight up at the apex where it has none
+                let gradient = Vec3::new(p.x(), p.y(), k2 * (self.height - p.z()));
+                let normal = if gradient.near_zero() {
+                    Vec3::new(0.0, 0.0, 1.0)
+                } else {
+                    gradient.unit_vector()
+                };
+
+                closest = root;
+                found = Some((normal, u, p.z() / self.height));
+                break;
+            }
+        }
+
+        // The base disk
+        if d.z() != 0.0 {
+            let root = -o.z() / d.z();
+            let p = o + root * d;
+            let distance = (p.x() * p.x() + p.y() * p.y()).sqrt();
+
+            if Interval::new(ray_t.min, closest).surrounds(root) && distance <= self.radius {
+                let u = turn_fraction(p.x(), p.y());
+
+                closest = root;
+                found = Some((Vec3::new(0.0, 0.0, -1.0), u, distance / self.radius));
+            }
+        }
+
+        let Some((local_normal, u, v)) = found else {
+            return false;
+        };
+
+        hit_record.t = closest;
+        hit_record.p = ray.at(closest);
+        hit_record.u = u;
+        hit_record.v = v;
+        hit_record.mat = self.mat.clone();
+        hit_record.set_face_normal(ray, &self.frame.transform(&local_normal));
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
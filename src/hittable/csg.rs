@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use crate::{aabb::Aabb, interval::Interval, ray::Ray};
+
+use super::{HitRecord, Hittable, HittableObject, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CsgOperation {
+    Union,
+    Intersection,
+    // Everything in the left solid that is not in the right one
+    Difference,
+}
+
+impl CsgOperation {
+    fn contains(self, in_left: bool, in_right: bool) -> bool {
+        match self {
+            CsgOperation::Union => in_left || in_right,
+            CsgOperation::Intersection => in_left && in_right,
+            CsgOperation::Difference => in_left && !in_right,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Csg {
+    // Closed solids combined by the operation, which may themselves be combinations
+    left: Arc<HittableObject>,
+    right: Arc<HittableObject>,
+    operation: CsgOperation,
+    bbox: Aabb,
+}
+
+impl Csg {
+    pub fn new(left: HittableObject, right: HittableObject, operation: CsgOperation) -> Self {
+        let bbox = match operation {
+            CsgOperation::Union => Aabb::from_aabbs(left.bounding_box(), right.bounding_box()),
+            CsgOperation::Intersection => Aabb::overlap(left.bounding_box(), right.bounding_box()),
+            CsgOperation::Difference => *left.bounding_box(),
+        };
+
+        Self {
+            left: Arc::new(left),
+            right: Arc::new(right),
+            operation,
+            bbox,
+        }
+    }
+}
+
+impl Hittable for Csg {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        if !self.bbox.hit(ray, *ray_t) {
+            return false;
+        }
+
+        // The first boundary of the combined solid inside the interval
+        let Some(rec) = self
+            .intervals(ray)
+            .into_iter()
+            .flat_map(|span| [span.enter, span.exit])
+            .find(|rec| ray_t.surrounds(rec.t))
+        else {
+            return false;
+        };
+
+        *hit_record = rec;
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+
+    fn intervals(&self, ray: &Ray) -> Vec<Span> {
+        // Sweep through the crossings of both solids in order along the ray, keeping track of
+        // which ones it is inside, and keep those where that changes whether it is in the result
+        let mut crossings = vec![];
+        for (spans, on_left) in [
+            (self.left.intervals(ray), true),
+            (self.right.intervals(ray), false),
+        ] {
+            for span in spans {
+                crossings.push((span.enter, on_left, true));
+                crossings.push((span.exit, on_left, false));
+            }
+        }
+        crossings.sort_by(|a, b| a.0.t.total_cmp(&b.0.t));
+
+        let mut spans = vec![];
+        let mut enter = None;
+        let (mut in_left, mut in_right) = (false, false);
+
+        for (mut rec, on_left, entering) in crossings {
+            if on_left {
+                in_left = entering;
+            } else {
+                in_right = entering;
+            }
+
+            let inside = self.operation.contains(in_left, in_right);
+            if inside == enter.is_some() {
+                continue;
+            }
+
+            // The surface keeps its material and its normal facing the ray, but whether the ray
+            // is going in now depends on the result, as where it leaves a cutter it enters a
+            // difference
+            rec.front_face = inside;
+            match enter.take() {
+                None => enter = Some(rec),
+                Some(enter) => spans.push(Span { enter, exit: rec }),
+            }
+        }
+
+        spans
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        material::MaterialType,
+        vec3::{Point3, Vec3},
+    };
+
+    use super::*;
+
+    fn sphere(x: f32, radius: f32) -> HittableObject {
+        HittableObject::stationary_sphere(Point3::new(x, 0.0, 0.0), radius, MaterialType::None)
+    }
+
+    fn crossings(object: &HittableObject) -> Vec<(f32, f32)> {
+        // Spans along the x axis, from a ray starting at x = -10
+        let ray = Ray::new(
+            &Point3::new(-10.0, 0.0, 0.0),
+            &Vec3::new(1.0, 0.0, 0.0),
+            0.0,
+        );
+        object
+            .intervals(&ray)
+            .iter()
+            .map(|span| (span.enter.t - 10.0, span.exit.t - 10.0))
+            .collect()
+    }
+
+    fn assert_spans(object: &HittableObject, expected: &[(f32, f32)]) {
+        let spans = crossings(object);
+        assert_eq!(spans.len(), expected.len(), "{spans:?}");
+        for (span, expected) in spans.iter().zip(expected) {
+            assert!((span.0 - expected.0).abs() < 1e-4, "{spans:?}");
+            assert!((span.1 - expected.1).abs() < 1e-4, "{spans:?}");
+        }
+    }
+
+    #[test]
+    fn combines_spans() {
+        // Spheres spanning -1..1 and 0..4 along x
+        assert_spans(
+            &HittableObject::union(sphere(0.0, 1.0), sphere(2.0, 2.0)),
+            &[(-1.0, 4.0)],
+        );
+        assert_spans(
+            &HittableObject::intersection(sphere(0.0, 1.0), sphere(2.0, 2.0)),
+            &[(0.0, 1.0)],
+        );
+        assert_spans(
+            &HittableObject::difference(sphere(2.0, 2.0), sphere(0.0, 1.0)),
+            &[(1.0, 4.0)],
+        );
+
+        // A shell, with the hole splitting the ray into two stretches
+        assert_spans(
+            &HittableObject::difference(sphere(0.0, 2.0), sphere(0.0, 1.0)),
+            &[(-2.0, -1.0), (1.0, 2.0)],
+        );
+    }
+
+    #[test]
+    fn hits_the_cut_surface_from_inside_the_cutter() {
+        let shell = HittableObject::difference(sphere(0.0, 2.0), sphere(0.0, 1.0));
+
+        // Starting in the hole, the ray enters the shell through the surface of the cutter
+        let ray = Ray::new(&Point3::default(), &Vec3::new(1.0, 0.0, 0.0), 0.0);
+        let mut rec = HitRecord::default();
+        assert!(shell.hit(&ray, &Interval::new(0.001, f32::INFINITY), &mut rec));
+        assert!((rec.t - 1.0).abs() < 1e-4);
+        assert!(rec.front_face);
+        assert!((rec.normal.x() + 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn open_surfaces_are_not_solids() {
+        // A quad across the sphere neither adds to it nor cuts it
+        let quad = || {
+            HittableObject::quad(
+                Point3::new(0.5, -2.0, -2.0),
+                Vec3::new(0.0, 4.0, 0.0),
+                Vec3::new(0.0, 0.0, 4.0),
+                MaterialType::None,
+            )
+        };
+        assert_spans(
+            &HittableObject::union(sphere(0.0, 1.0), quad()),
+            &[(-1.0, 1.0)],
+        );
+        assert_spans(
+            &HittableObject::difference(sphere(0.0, 1.0), quad()),
+            &[(-1.0, 1.0)],
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use crate::{
+    aabb::Aabb,
+    interval::Interval,
+    material::MaterialType,
+    onb::Onb,
+    ray::Ray,
+    utility::turn_fraction,
+    vec3::{Point3, Vec3},
+};
+
+use super::{HitRecord, Hittable};
+
+#[derive(Clone)]
+pub struct Cylinder {
+    // Center of the bottom end, with the axis running up w of the frame
+    base: Point3,
+    frame: Onb,
+    radius: f32,
+    height: f32,
+    // Whether the ends are closed by disks, or left open like a pipe
+    capped: bool,
+    mat: Arc<MaterialType>,
+    bbox: Aabb,
+}
+
+impl Cylinder {
+    pub fn new(base: Point3, top: Point3, radius: f32, capped: bool, mat: MaterialType) -> Self {
+        let radius = radius.max(0.0);
+        let axis = top - base;
+
+        let bbox = Aabb::from_aabbs(
+            &Aabb::from_circle(&base, &axis, radius),
+            &Aabb::from_circle(&top, &axis, radius),
+        );
+
+        Self {
+            base,
+            frame: Onb::new(&axis),
+            radius,
+            height: axis.length(),
+            capped,
+            mat: Arc::new(mat),
+            bbox,
+        }
+    }
+
+    pub fn is_capped(&self) -> bool {
+        self.capped
+    }
+}
+
+impl Hittable for Cylinder {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        // Intersect in the frame of the cylinder, where the axis is z from the base
+        let o = self.frame.untransform(&(*ray.origin() - self.base));
+        let d = self.frame.untransform(ray.direction());
+
+        let mut closest = ray_t.max;
+        let mut found = None;
+
+        // The side, where x^2 + y^2 = r^2 between the ends
+        let a = d.x() * d.x() + d.y() * d.y();
+        if a > 0.0 {
+            let h = -(o.x() * d.x() + o.y() * d.y());
+            let c = o.x() * o.x() + o.y() * o.y() - self.radius * self.radius;
+            let discriminant = h * h - a * c;
+
+            if discriminant >= 0.0 {
+                let sqrtd = discriminant.sqrt();
+                for root in [(h - sqrtd) / a, (h + sqrtd) / a] {
+                    let z = o.z() + root * d.z();
+                    if Interval::new(ray_t.min, closest).surrounds(root)
+                        && (0.0..=self.height).contains(&z)
+                    {
+                        let p = o + root * d;
+                        let u = turn_fraction(p.x(), p.y());
+
+                        closest = root;
+                        found = Some((
+                            Vec3::new(p.x(), p.y(), 0.0) / self.radius,
+                            u,
+                            z / self.height,
+                        ));
+                        break;
+                    }
+                }
+            }
+        }
+
+        // The end disks
+        if self.capped && d.z() != 0.0 {
+            for (z, normal) in [(0.0, -1.0), (self.height, 1.0)] {
+                let root = (z - o.z()) / d.z();
+                let p = o + root * d;
+                let distance = (p.x() * p.x() + p.y() * p.y()).sqrt();
+
+                if Interval::new(ray_t.min, closest).surrounds(root) && distance <= self.radius {
+                    let u = turn_fraction(p.x(), p.y());
+
+                    closest = root;
+                    found = Some((Vec3::new(0.0, 0.0, normal), u, distance / self.radius));
+                }
+            }
+        }
+
+        let Some((local_normal, u, v)) = found else {
+            return false;
+        };
+
+        hit_record.t = closest;
+        hit_record.p = ray.at(closest);
+        hit_record.u = u;
+        hit_record.v = v;
+        hit_record.mat = self.mat.clone();
+        hit_record.set_face_normal(ray, &self.frame.transform(&local_normal));
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use crate::{
+    aabb::Aabb,
+    interval::Interval,
+    material::MaterialType,
+    onb::Onb,
+    ray::Ray,
+    utility::turn_fraction,
+    vec3::{Point3, Vec3},
+};
+
+use super::{HitRecord, Hittable};
+
+#[derive(Clone)]
+pub struct Disk {
+    center: Point3,
+    radius: f32,
+    // Basis with w along the normal, measuring angles around the disk from u
+    frame: Onb,
+    mat: Arc<MaterialType>,
+    bbox: Aabb,
+}
+
+impl Disk {
+    pub fn new(center: Point3, normal: Vec3, radius: f32, mat: MaterialType) -> Self {
+        let radius = radius.max(0.0);
+
+        Self {
+            center,
+            radius,
+            frame: Onb::new(&normal),
+            mat: Arc::new(mat),
+            bbox: Aabb::from_circle(&center, &normal, radius),
+        }
+    }
+}
+
+impl Hittable for Disk {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        let normal = self.frame.w();
+        let denom = normal.dot(ray.direction());
+
+        // No hit if the ray is parallel to the disk
+        if denom.abs() < 1e-8 {
+            return false;
+        }
+
+        let t = normal.dot(&(self.center - ray.origin())) / denom;
+        if !ray_t.contains(t) {
+            return false;
+        }
+
+        let p = ray.at(t);
+        let local = self.frame.untransform(&(p - self.center));
+        let distance = (local.x() * local.x() + local.y() * local.y()).sqrt();
+        if distance > self.radius {
+            return false;
+        }
+
+        // u runs around the disk and v out from its center
+        hit_record.u = turn_fraction(local.x(), local.y());
+        hit_record.v = distance / self.radius;
+
+        hit_record.t = t;
+        hit_record.p = p;
+        hit_record.mat = self.mat.clone();
+        hit_record.set_face_normal(ray, normal);
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use image::DynamicImage;
+
+use crate::{
+    aabb::Aabb,
+    interval::Interval,
+    material::MaterialType,
+    ray::Ray,
+    vec3::{Point3, Vec3},
+};
+
+use super::{HitRecord, Hittable};
+
+#[derive(Clone)]
+pub struct Heightfield {
+    // Corner of the terrain at its lowest, with the grid laid out along x and z from it
+    corner: Point3,
+    // Number of samples along x and z, making one cell fewer each way
+    columns: usize,
+    rows: usize,
+    // Spacing of the samples along x and z
+    cell_x: f32,
+    cell_z: f32,
+    // Height above the corner and smooth normal at each sample, row by row
+    heights: Vec<f32>,
+    normals: Vec<Vec3>,
+    // Lowest and highest point of each cell, to skip cells the ray passes over
+    cell_bounds: Vec<Interval>,
+    mat: Arc<MaterialType>,
+    bbox: Aabb,
+}
+
+impl Heightfield {
+    pub fn new(image: &DynamicImage, corner: Point3, size: Vec3, mat: MaterialType) -> Self {
+        // Brightness of each pixel is the height, from the corner up to the height of `size`.
+        // The terrain covers size.x by size.z, reading like the image when seen from above with
+        // its top row along the corner.
+        let luma = image.to_luma32f();
+        let (columns, rows) = (luma.width().max(2) as usize, luma.height().max(2) as usize);
+        let heights: Vec<f32> = (0..rows)
+            .flat_map(|j| (0..columns).map(move |i| (i, j)))
+            .map(|(i, j)| {
+                let x = (i as u32).min(luma.width() - 1);
+                let y = (j as u32).min(luma.height() - 1);
+                luma.get_pixel(x, y)[0] * size.y()
+            })
+            .collect();
+
+        let cell_x = size.x() / (columns - 1) as f32;
+        let cell_z = size.z() / (rows - 1) as f32;
+        let height = |i: usize, j: usize| heights[j * columns + i];
+
+        // Normals from the slope of the heights around each sample, one sided at the edges
+        let normals = (0..rows)
+            .flat_map(|j| (0..columns).map(move |i| (i, j)))
+            .map(|(i, j)| {
+                let (left, right) = (i.saturating_sub(1), (i + 1).min(columns - 1));
+                let (near, far) = (j.saturating_sub(1), (j + 1).min(rows - 1));
+                let slope_x =
+                    (height(right, j) - height(left, j)) / ((right - left) as f32 * cell_x);
+                let slope_z = (height(i, far) - height(i, near)) / ((far - near) as f32 * cell_z);
+                Vec3::new(-slope_x, 1.0, -slope_z).unit_vector()
+            })
+            .collect();
+
+        let cell_bounds = (0..rows - 1)
+            .flat_map(|j| (0..columns - 1).map(move |i| (i, j)))
+            .map(|(i, j)| {
+                let corners = [
+                    height(i, j),
+                    height(i + 1, j),
+                    height(i, j + 1),
+                    height(i + 1, j + 1),
+                ];
+                Interval::new(
+                    corners.iter().copied().fold(f32::INFINITY, f32::min),
+                    corners.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+                )
+            })
+            .collect();
+
+        let top = heights.iter().copied().fold(0.0, f32::max);
+        let bbox = Aabb::from_points(&corner, &(corner + Vec3::new(size.x(), top, size.z())));
+
+        Self {
+            corner,
+            columns,
+            rows,
+            cell_x,
+            cell_z,
+            heights,
+            normals,
+            cell_bounds,
+            mat: Arc::new(mat),
+            bbox,
+        }
+    }
+
+    fn vertex(&self, i: usize, j: usize) -> Point3 {
+        self.corner
+            + Vec3::new(
+                i as f32 * self.cell_x,
+                self.heights[j * self.columns + i],
+                j as f32 * self.cell_z,
+            )
+    }
+
+    fn hit_cell(
+        &self,
+        i: usize,
+        j: usize,
+        ray: &Ray,
+        ray_t: &Interval,
+        hit_record: &mut HitRecord,
+    ) -> bool {
+        // The two triangles either side of the diagonal from (i + 1, j) to (i, j + 1)
+        let corners = [(i, j), (i + 1, j), (i, j + 1), (i + 1, j + 1)];
+        let mut closest = ray_t.max;
+        let mut found = false;
+
+        for triangle in [[0, 1, 2], [3, 2, 1]] {
+            let [a, b, c] = triangle.map(|k| corners[k]);
+            let Some((t, beta, gamma)) = intersect_triangle(
+                ray,
+                &self.vertex(a.0, a.1),
+                &self.vertex(b.0, b.1),
+                &self.vertex(c.0, c.1),
+            ) else {
+                continue;
+            };
+            if !Interval::new(ray_t.min, closest).surrounds(t) {
+                continue;
+            }
+
+            let p = ray.at(t);
+            let normal_at = |(i, j): (usize, usize)| self.normals[j * self.columns + i];
+            let smooth =
+                ((1.0 - beta - gamma) * normal_at(a) + beta * normal_at(b) + gamma * normal_at(c))
+                    .unit_vector();
+
+            // Which side the ray is on comes from the flat triangle, the shading from the
+            // interpolated normal on that side
+            let geometric = (self.vertex(c.0, c.1) - self.vertex(a.0, a.1))
+                .cross(&(self.vertex(b.0, b.1) - self.vertex(a.0, a.1)));
+            hit_record.set_face_normal(ray, &geometric.unit_vector());
+            hit_record.normal = if hit_record.front_face {
+                smooth
+            } else {
+                -smooth
+            };
+
+            // The whole image spans the terrain, with v running up it towards the corner
+            hit_record.u = (p.x() - self.corner.x()) / (self.cell_x * (self.columns - 1) as f32);
+            hit_record.v = 1.0 - (p.z() - self.corner.z()) / (self.cell_z * (self.rows - 1) as f32);
+
+            hit_record.t = t;
+            hit_record.p = p;
+            hit_record.mat = self.mat.clone();
+            closest = t;
+            found = true;
+        }
+
+        found
+    }
+}
+
+impl Hittable for Heightfield {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        let Some(span) = self.bbox.clip(ray, *ray_t) else {
+            return false;
+        };
+
+        // Walk the cells under the ray in order across the grid, so the first hit is the closest
+        let origin = *ray.origin() - self.corner;
+        let direction = ray.direction();
+        let entry = origin + span.min * *direction;
+
+        let last = [self.columns - 2, self.rows - 2];
+        let cell_size = [self.cell_x, self.cell_z];
+        let (o, d) = ([entry.x(), entry.z()], [direction.x(), direction.z()]);
+
+        let mut cell = [0; 2];
+        let mut step = [0isize; 2];
+        let mut t_next = [f32::INFINITY; 2];
+        let mut t_delta = [f32::INFINITY; 2];
+        for axis in 0..2 {
+            cell[axis] = ((o[axis] / cell_size[axis]).floor().max(0.0) as usize).min(last[axis]);
+            if d[axis] > 0.0 {
+                step[axis] = 1;
+                t_delta[axis] = cell_size[axis] / d[axis];
+                t_next[axis] =
+                    span.min + ((cell[axis] + 1) as f32 * cell_size[axis] - o[axis]) / d[axis];
+            } else if d[axis] < 0.0 {
+                step[axis] = -1;
+                t_delta[axis] = -cell_size[axis] / d[axis];
+                t_next[axis] = span.min + (cell[axis] as f32 * cell_size[axis] - o[axis]) / d[axis];
+            }
+        }
+
+        let mut t_enter = span.min;
+        while t_enter <= span.max {
+            let t_exit = t_next[0].min(t_next[1]).min(span.max);
+
+            // Skip the cell if the ray stays above or below all of it
+            let heights = &self.cell_bounds[cell[1] * (self.columns - 1) + cell[0]];
+            let (y0, y1) = (
+                origin.y() + t_enter * direction.y(),
+                origin.y() + t_exit * direction.y(),
+            );
+            if y0.min(y1) <= heights.max + 1e-4
+                && y0.max(y1) >= heights.min - 1e-4
+                && self.hit_cell(cell[0], cell[1], ray, ray_t, hit_record)
+            {
+                return true;
+            }
+
+            let axis = if t_next[0] < t_next[1] { 0 } else { 1 };
+            let next = cell[axis] as isize + step[axis];
+            if next < 0 || next > last[axis] as isize {
+                return false;
+            }
+            cell[axis] = next as usize;
+            t_enter = t_next[axis];
+            t_next[axis] += t_delta[axis];
+        }
+
+        false
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
+
+fn intersect_triangle(ray: &Ray, a: &Point3, b: &Point3, c: &Point3) -> Option<(f32, f32, f32)> {
+    // Möller-Trumbore, giving the ray parameter and the barycentric weights of b and c
+    let edge1 = *b - *a;
+    let edge2 = *c - *a;
+    let p = ray.direction().cross(&edge2);
+    let det = edge1.dot(&p);
+    if det.abs() < 1e-12 {
+        return None;
+    }
+
+    let inv_det = 1.0 / det;
+    let s = *ray.origin() - *a;
+    let beta = s.dot(&p) * inv_det;
+    if !(0.0..=1.0).contains(&beta) {
+        return None;
+    }
+
+    let q = s.cross(&edge1);
+    let gamma = ray.direction().dot(&q) * inv_det;
+    if gamma < 0.0 || beta + gamma > 1.0 {
+        return None;
+    }
+
+    Some((edge2.dot(&q) * inv_det, beta, gamma))
+}
+
+#[cfg(test)]
+mod tests {
+    use image::GrayImage;
+
+    use super::*;
+
+    #[test]
+    fn finds_the_first_cell_hit_along_the_grid() {
+        // A ridge across the middle of a flat 5 x 5 field, one unit per cell and four high
+        let image = GrayImage::from_fn(5, 5, |x, _| image::Luma([if x == 2 { 255 } else { 0 }]));
+        let field = Heightfield::new(
+            &DynamicImage::ImageLuma8(image),
+            Point3::default(),
+            Vec3::new(4.0, 4.0, 4.0),
+            MaterialType::None,
+        );
+        let t_range = Interval::new(0.001, f32::INFINITY);
+
+        // Skimming along x at height one hits the near slope of the ridge, at x = 1.25
+        let ray = Ray::new(&Point3::new(-1.0, 1.0, 1.5), &Vec3::new(1.0, 0.0, 0.0), 0.0);
+        let mut rec = HitRecord::default();
+        assert!(field.hit(&ray, &t_range, &mut rec));
+        assert!((rec.p.x() - 1.25).abs() < 1e-4);
+        assert!(rec.front_face);
+
+        // Straight down onto the flat part, which the image maps onto at its right edge
+        let ray = Ray::new(
+            &Point3::new(3.75, 9.0, 2.0),
+            &Vec3::new(0.0, -1.0, 0.0),
+            0.0,
+        );
+        assert!(field.hit(&ray, &t_range, &mut rec));
+        assert!(rec.p.y().abs() < 1e-4);
+        assert!((rec.u - 0.9375).abs() < 1e-4 && (rec.v - 0.5).abs() < 1e-4);
+
+        // Passing above the ridge
+        let ray = Ray::new(&Point3::new(-1.0, 4.5, 1.5), &Vec3::new(1.0, 0.0, 0.0), 0.0);
+        assert!(!field.hit(&ray, &t_range, &mut rec));
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use crate::{aabb::Aabb, interval::Interval, mat4::Mat4, material::MaterialType, ray::Ray};
+
+use super::{transform::Transform, HitRecord, Hittable, HittableObject};
+
+#[derive(Clone)]
+pub struct Instance {
+    // Placement of geometry shared with every other instance of it
+    transform: Transform,
+    // Material used for all of this instance instead of the geometry's own
+    material: Option<Arc<MaterialType>>,
+}
+
+impl Instance {
+    pub fn new(
+        geometry: &Arc<HittableObject>,
+        matrix: Mat4,
+        material: Option<Arc<MaterialType>>,
+    ) -> Self {
+        Self {
+            transform: Transform::shared(geometry.clone(), matrix),
+            material,
+        }
+    }
+}
+
+impl Hittable for Instance {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        if !self.transform.hit(ray, ray_t, hit_record) {
+            return false;
+        }
+
+        if let Some(material) = &self.material {
+            hit_record.mat = material.clone();
+        }
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        self.transform.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::Color,
+        texture::TextureType,
+        vec3::{Point3, Vec3},
+    };
+
+    use super::*;
+
+    fn first_hit(object: &HittableObject, direction: Vec3) -> Option<HitRecord> {
+        let ray = Ray::new(&Point3::default(), &direction, 0.0);
+        let mut rec = HitRecord::default();
+        object
+            .hit(&ray, &Interval::new(0.001, f32::INFINITY), &mut rec)
+            .then_some(rec)
+    }
+
+    #[test]
+    fn instances_share_geometry_but_not_placement_or_material() {
+        let geometry = Arc::new(HittableObject::stationary_sphere(
+            Point3::default(),
+            1.0,
+            MaterialType::None,
+        ));
+        let red = Arc::new(MaterialType::lambertion(TextureType::from(Color::new(
+            1.0, 0.0, 0.0,
+        ))));
+
+        // Sphere of radius 2 at x = 5 painted red, and one of radius 1 at x = -5 keeping the
+        // geometry's material
+        let right = HittableObject::instance(
+            &geometry,
+            Mat4::translation(Vec3::new(5.0, 0.0, 0.0)) * Mat4::scaling(Vec3::new(2.0, 2.0, 2.0)),
+            Some(red.clone()),
+        );
+        let left = HittableObject::instance(
+            &geometry,
+            Mat4::translation(Vec3::new(-5.0, 0.0, 0.0)),
+            None,
+        );
+
+        let rec = first_hit(&right, Vec3::new(1.0, 0.0, 0.0)).unwrap();
+        assert!((rec.t - 3.0).abs() < 1e-4);
+        assert!((rec.normal.x() + 1.0).abs() < 1e-4);
+        assert!(Arc::ptr_eq(&rec.mat, &red));
+
+        let rec = first_hit(&left, Vec3::new(-1.0, 0.0, 0.0)).unwrap();
+        assert!((rec.t - 4.0).abs() < 1e-4);
+        assert!(Arc::ptr_eq(&rec.mat, geometry.material().unwrap()));
+
+        // Neither instance is where the other one was placed
+        assert!(first_hit(&right, Vec3::new(-1.0, 0.0, 0.0)).is_none());
+        assert!(first_hit(&left, Vec3::new(1.0, 0.0, 0.0)).is_none());
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use crate::{
+    aabb::Aabb,
+    interval::Interval,
+    material::MaterialType,
+    onb::Onb,
+    ray::Ray,
+    vec3::{Point3, Vec3},
+};
+
+use super::{HitRecord, Hittable};
+
+#[derive(Clone)]
+pub struct Plane {
+    // Any point on the plane, from which the texture is laid out
+    point: Point3,
+    // Basis with w along the normal, with u and v across the plane
+    frame: Onb,
+    d: f32,
+    // Length along the plane over which the texture coordinates wrap around
+    tile_size: f32,
+    mat: Arc<MaterialType>,
+}
+
+impl Plane {
+    pub fn new(point: Point3, normal: Vec3, tile_size: f32, mat: MaterialType) -> Self {
+        let frame = Onb::new(&normal);
+
+        Self {
+            point,
+            frame,
+            d: frame.w().dot(&point),
+            // Texture coordinates are divided by the tile size, so keep it away from zero
+            tile_size: tile_size.max(1e-4),
+            mat: Arc::new(mat),
+        }
+    }
+}
+
+impl Hittable for Plane {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        let normal = self.frame.w();
+        let denom = normal.dot(ray.direction());
+
+        // No hit if the ray is parallel to the plane
+        if denom.abs() < 1e-8 {
+            return false;
+        }
+
+        let t = (self.d - normal.dot(ray.origin())) / denom;
+        if !ray_t.contains(t) {
+            return false;
+        }
+
+        // Snap the hit point onto the plane, so solid textures changing across it don't flicker
+        // from one side to the other with rounding error
+        let p = ray.at(t);
+        let p = p - (normal.dot(&p) - self.d) * *normal;
+
+        // Planar mapping, repeating every tile along u and v of the frame
+        let local = self.frame.untransform(&(p - self.point)) / self.tile_size;
+        hit_record.u = local.x().rem_euclid(1.0);
+        hit_record.v = local.y().rem_euclid(1.0);
+
+        hit_record.t = t;
+        hit_record.p = p;
+        hit_record.mat = self.mat.clone();
+        hit_record.set_face_normal(ray, normal);
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        // Reaches out forever, so bounding volume hierarchies keep it apart from everything else
+        &Aabb::UNIVERSE
+    }
+}
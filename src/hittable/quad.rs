@@ -1,9 +1,11 @@
-use std::sync::Arc;
+use std::{f32::consts::PI, sync::Arc};
 
 use crate::{
     aabb::Aabb,
     interval::Interval,
     material::MaterialType,
+    ray::Ray,
+    utility::{random_float, turn_fraction},
     vec3::{Point3, Vec3},
 };
 
@@ -11,34 +13,76 @@ use super::{HitRecord, Hittable};
 
 const UNIT_INTERVAL: Interval = Interval { min: 0.0, max: 1.0 };
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlanarShape {
+    // The parallelogram with corners q, q + u, q + v and q + u + v
+    Parallelogram,
+    // The half of the parallelogram on the q side of its diagonal from q + u to q + v
+    Triangle,
+    // Centered on q, with u and v as the two semi-axes
+    Ellipse,
+    // An ellipse with a hole, whose size is a fraction of the outer edge
+    Annulus { inner: f32 },
+}
+
 #[derive(Clone)]
 pub struct Quad {
     q: Point3,
     u: Vec3,
     v: Vec3,
+    shape: PlanarShape,
     mat: Arc<MaterialType>,
     bbox: Aabb,
     normal: Vec3,
     d: f32,
     w: Vec3,
+    area: f32,
 }
 
 impl Quad {
     pub fn new(q: Point3, u: Vec3, v: Vec3, mat: MaterialType) -> Quad {
+        Self::with_shape(q, u, v, PlanarShape::Parallelogram, mat)
+    }
+
+    pub fn triangle(q: Point3, u: Vec3, v: Vec3, mat: MaterialType) -> Quad {
+        Self::with_shape(q, u, v, PlanarShape::Triangle, mat)
+    }
+
+    pub fn ellipse(center: Point3, u: Vec3, v: Vec3, mat: MaterialType) -> Quad {
+        Self::with_shape(center, u, v, PlanarShape::Ellipse, mat)
+    }
+
+    pub fn annulus(center: Point3, u: Vec3, v: Vec3, inner: f32, mat: MaterialType) -> Quad {
+        let inner = inner.clamp(0.0, 1.0);
+        Self::with_shape(center, u, v, PlanarShape::Annulus { inner }, mat)
+    }
+
+    fn with_shape(q: Point3, u: Vec3, v: Vec3, shape: PlanarShape, mat: MaterialType) -> Quad {
         let n = u.cross(&v);
         let normal = n.unit_vector();
         let d = normal.dot(&q);
         let w = n / n.dot(&n);
 
+        // Area of the shape as a fraction of the parallelogram spanned by u and v
+        let area = n.length()
+            * match shape {
+                PlanarShape::Parallelogram => 1.0,
+                PlanarShape::Triangle => 0.5,
+                PlanarShape::Ellipse => PI,
+                PlanarShape::Annulus { inner } => PI * (1.0 - inner * inner),
+            };
+
         let mut tmp = Self {
             q,
             u,
             v,
+            shape,
             mat: Arc::new(mat),
             bbox: Aabb::default(),
             normal,
             d,
             w,
+            area,
         };
 
         tmp.set_bounding_box();
@@ -46,23 +90,78 @@ impl Quad {
         tmp
     }
 
+    pub fn material(&self) -> &Arc<MaterialType> {
+        &self.mat
+    }
+
+    pub fn normal(&self) -> &Vec3 {
+        &self.normal
+    }
+
+    pub fn area(&self) -> f32 {
+        self.area
+    }
+
     fn set_bounding_box(&mut self) {
-        // Compute the bounding box of all four vertices
-        let bbox_diagonal1 = Aabb::from_points(&self.q, &(self.q + self.u + self.v));
-        let bbox_diagonal2 = Aabb::from_points(&(self.q + self.u), &(self.q + self.v));
-        self.bbox = Aabb::from_aabbs(&bbox_diagonal1, &bbox_diagonal2);
+        // Compute the bounding box of all four vertices of the parallelogram holding the shape,
+        // which for the ellipses reaches from q - u - v to q + u + v
+        let (q, u, v) = (self.q, self.u, self.v);
+        self.bbox = match self.shape {
+            PlanarShape::Parallelogram => Aabb::from_aabbs(
+                &Aabb::from_points(&q, &(q + u + v)),
+                &Aabb::from_points(&(q + u), &(q + v)),
+            ),
+            PlanarShape::Triangle => Aabb::from_aabbs(
+                &Aabb::from_points(&q, &(q + u)),
+                &Aabb::from_points(&q, &(q + v)),
+            ),
+            PlanarShape::Ellipse | PlanarShape::Annulus { .. } => Aabb::from_aabbs(
+                &Aabb::from_points(&(q - u - v), &(q + u + v)),
+                &Aabb::from_points(&(q + u - v), &(q - u + v)),
+            ),
+        };
     }
 
     fn is_interior(&self, a: f32, b: f32, rec: &mut HitRecord) -> bool {
         // Given the hit point in plane coordinates, return false if it is outside the primitive,
-        // otherwise set the hit record UV coordinates nd return true
-
-        if !UNIT_INTERVAL.contains(a) || !UNIT_INTERVAL.contains(b) {
-            return false;
-        }
+        // otherwise set the hit record UV coordinates and return true
+        let (u, v) = match self.shape {
+            PlanarShape::Parallelogram => {
+                if !UNIT_INTERVAL.contains(a) || !UNIT_INTERVAL.contains(b) {
+                    return false;
+                }
+                (a, b)
+            }
+            PlanarShape::Triangle => {
+                if a < 0.0 || b < 0.0 || a + b > 1.0 {
+                    return false;
+                }
+                (a, b)
+            }
+            PlanarShape::Ellipse => {
+                if a * a + b * b > 1.0 {
+                    return false;
+                }
+                (a / 2.0 + 0.5, b / 2.0 + 0.5)
+            }
+            PlanarShape::Annulus { inner } => {
+                // u runs around the ring and v across it, from the inner edge
+                let radius = (a * a + b * b).sqrt();
+                if radius < inner || radius > 1.0 {
+                    return false;
+                }
+                let width = 1.0 - inner;
+                let across = if width > 0.0 {
+                    (radius - inner) / width
+                } else {
+                    0.0
+                };
+                (turn_fraction(a, b), across)
+            }
+        };
 
-        rec.u = a;
-        rec.v = b;
+        rec.u = u;
+        rec.v = v;
 
         true
     }
@@ -110,4 +209,85 @@ impl Hittable for Quad {
     fn bounding_box(&self) -> &Aabb {
         &self.bbox
     }
+
+    fn pdf_value(&self, origin: &Point3, direction: &Vec3) -> f32 {
+        let mut rec = HitRecord::default();
+        if !self.hit(
+            &Ray::new(origin, direction, 0.0),
+            &Interval::new(0.001, f32::INFINITY),
+            &mut rec,
+        ) {
+            return 0.0;
+        }
+
+        // Convert the uniform density over the area to a density over solid angle
+        let distance_squared = rec.t * rec.t * direction.length_squared();
+        let cosine = (direction.dot(&self.normal) / direction.length()).abs();
+
+        distance_squared / (cosine * self.area)
+    }
+
+    fn random(&self, origin: &Point3) -> Vec3 {
+        // A point spread uniformly over the shape, in its plane coordinates
+        let (a, b) = match self.shape {
+            PlanarShape::Parallelogram => (random_float(), random_float()),
+            PlanarShape::Triangle => {
+                // Points beyond the diagonal fold back onto the triangle
+                let (a, b) = (random_float(), random_float());
+                if a + b > 1.0 {
+                    (1.0 - a, 1.0 - b)
+                } else {
+                    (a, b)
+                }
+            }
+            PlanarShape::Ellipse | PlanarShape::Annulus { .. } => {
+                let inner = match self.shape {
+                    PlanarShape::Annulus { inner } => inner,
+                    _ => 0.0,
+                };
+                let radius = (inner * inner + (1.0 - inner * inner) * random_float()).sqrt();
+                let (sin_phi, cos_phi) = (2.0 * PI * random_float()).sin_cos();
+                (radius * cos_phi, radius * sin_phi)
+            }
+        };
+
+        let p = self.q + (a * self.u) + (b * self.v);
+        p - origin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_land_on_the_shape() {
+        let (q, u, v) = (
+            Point3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.5, 1.0, 0.0),
+        );
+        let origin = Point3::new(0.3, 0.2, 3.0);
+        let t_range = Interval::new(0.001, f32::INFINITY);
+
+        for shape in [
+            Quad::new(q, u, v, MaterialType::None),
+            Quad::triangle(q, u, v, MaterialType::None),
+            Quad::ellipse(q, u, v, MaterialType::None),
+            Quad::annulus(q, u, v, 0.6, MaterialType::None),
+        ] {
+            for _ in 0..100 {
+                let direction = shape.random(&origin);
+                let ray = Ray::new(&origin, &direction, 0.0);
+                let mut rec = HitRecord::default();
+                assert!(shape.hit(&ray, &t_range, &mut rec), "{:?}", shape.shape);
+                assert!(shape.bounding_box().hit(&ray, t_range));
+            }
+        }
+
+        // The hole in the middle of the annulus
+        let annulus = Quad::annulus(q, u, v, 0.6, MaterialType::None);
+        let ray = Ray::new(&origin, &(q - origin), 0.0);
+        assert!(!annulus.hit(&ray, &t_range, &mut HitRecord::default()));
+    }
 }
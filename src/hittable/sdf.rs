@@ -0,0 +1,157 @@
+pub mod field;
+
+use std::sync::Arc;
+
+use crate::{
+    aabb::Aabb,
+    interval::Interval,
+    material::MaterialType,
+    ray::Ray,
+    vec3::{Point3, Vec3},
+};
+
+use field::DistanceField;
+
+use super::{HitRecord, Hittable};
+
+// Limit on the steps along a ray, which only runs out for rays skimming along the surface
+const MAX_STEPS: usize = 512;
+// Distance from the surface that counts as touching it
+const SURFACE_EPS: f32 = 1e-4;
+// Offset for the central differences estimating the gradient
+const GRADIENT_EPS: f32 = 1e-4;
+
+#[derive(Clone)]
+pub struct Sdf {
+    field: DistanceField,
+    mat: Arc<MaterialType>,
+    // Supplied by the user, as the surface of a field can't be bounded in general. Marching
+    // stays inside it, so anything sticking out is cut off.
+    bbox: Aabb,
+}
+
+impl Sdf {
+    pub fn new(field: DistanceField, bbox: Aabb, mat: MaterialType) -> Self {
+        Self {
+            field,
+            mat: Arc::new(mat),
+            bbox,
+        }
+    }
+
+    fn gradient(&self, p: &Point3) -> Vec3 {
+        // Central differences along each axis
+        let mut gradient = Vec3::default();
+        for i in 0..3 {
+            let mut offset = Vec3::default();
+            offset[i] = GRADIENT_EPS;
+            gradient[i] = self.field.distance(&(*p + offset)) - self.field.distance(&(*p - offset));
+        }
+
+        gradient
+    }
+}
+
+impl Hittable for Sdf {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        let Some(span) = self.bbox.clip(ray, *ray_t) else {
+            return false;
+        };
+
+        // Sphere tracing: the distance to the surface is a step that can't pass through it.
+        // Rays starting inside, like those refracted into glass, step out the same way.
+        // Marching from the start of the ray, the first step is never shorter than twice the
+        // tolerance, so rays leaving the surface don't find it again where they start.
+        let length = ray.direction().length();
+        let min_step = if span.min > ray_t.min {
+            0.0
+        } else {
+            2.0 * SURFACE_EPS
+        };
+        let start = self.field.distance(&ray.at(span.min)).abs();
+        let mut t = span.min + start.max(min_step) / length;
+        let mut steps = 1;
+        let p = loop {
+            if t > span.max || steps == MAX_STEPS {
+                return false;
+            }
+
+            let p = ray.at(t);
+            let distance = self.field.distance(&p).abs();
+            if distance < SURFACE_EPS {
+                break p;
+            }
+
+            t += distance / length;
+            steps += 1;
+        };
+
+        // A flat field, like the middle of a symmetric one, has no direction to face
+        let gradient = self.gradient(&p);
+        if !ray_t.surrounds(t) || gradient.length_squared() == 0.0 {
+            return false;
+        }
+
+        // Distance fields have no natural parameterisation, so textures work from the hit point
+        hit_record.u = 0.0;
+        hit_record.v = 0.0;
+
+        hit_record.t = t;
+        hit_record.p = p;
+        hit_record.mat = self.mat.clone();
+        hit_record.set_face_normal(ray, &gradient.unit_vector());
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traces_to_the_surface_from_both_sides() {
+        let sdf = Sdf::new(
+            DistanceField::sphere(1.0).translate(Vec3::new(0.0, 0.0, -3.0)),
+            Aabb::from_points(&Point3::new(-1.0, -1.0, -4.0), &Point3::new(1.0, 1.0, -2.0)),
+            MaterialType::None,
+        );
+        let t_range = Interval::new(0.001, f32::INFINITY);
+
+        let ray = Ray::new(&Point3::default(), &Vec3::new(0.0, 0.0, -2.0), 0.0);
+        let mut rec = HitRecord::default();
+        assert!(sdf.hit(&ray, &t_range, &mut rec));
+        assert!((rec.t - 1.0).abs() < 1e-3);
+        assert!((rec.normal.z() - 1.0).abs() < 1e-3);
+        assert!(rec.front_face);
+
+        // From the center, out through the far side
+        let ray = Ray::new(&Point3::new(0.0, 0.0, -3.0), &Vec3::new(0.0, 1.0, 0.0), 0.0);
+        assert!(sdf.hit(&ray, &t_range, &mut rec));
+        assert!((rec.p.y() - 1.0).abs() < 1e-3);
+        assert!(!rec.front_face);
+    }
+
+    #[test]
+    fn leaves_the_surface_it_starts_on() {
+        let sdf = Sdf::new(
+            DistanceField::sphere(1.0),
+            Aabb::from_points(&Point3::new(-2.0, -2.0, -2.0), &Point3::new(2.0, 2.0, 2.0)),
+            MaterialType::None,
+        );
+
+        // Starting on the top, within the tolerance of the surface, and heading away from it
+        let ray = Ray::new(&Point3::new(0.0, 1.0, 0.0), &Vec3::new(0.0, 1.0, 0.0), 0.0);
+        let mut rec = HitRecord::default();
+        assert!(!sdf.hit(&ray, &Interval::new(1e-5, f32::INFINITY), &mut rec));
+
+        // Heading back in, it finds the bottom instead
+        let ray = Ray::new(&Point3::new(0.0, 1.0, 0.0), &Vec3::new(0.0, -1.0, 0.0), 0.0);
+        assert!(sdf.hit(&ray, &Interval::new(1e-5, f32::INFINITY), &mut rec));
+        assert!((rec.t - 2.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,281 @@
+use std::sync::Arc;
+
+use crate::vec3::{Point3, Vec3};
+
+// Distance function supplied by the user, which must never overestimate the distance to the
+// surface
+pub type DistanceFn = dyn Fn(&Point3) -> f32 + Send + Sync;
+
+#[derive(Clone)]
+pub enum DistanceField {
+    // Primitives centered on the origin
+    Sphere {
+        radius: f32,
+    },
+    Cuboid {
+        half_size: Vec3,
+        // Rounding of the edges, taken out of the half size
+        rounding: f32,
+    },
+    // Lying in the xz plane, around the y axis
+    Torus {
+        major_radius: f32,
+        minor_radius: f32,
+    },
+    Capsule {
+        a: Point3,
+        b: Point3,
+        radius: f32,
+    },
+    // Escape time estimate for the Mandelbulb fractal, which for power eight fits inside a
+    // radius of about 1.2
+    Mandelbulb {
+        power: f32,
+        iterations: u32,
+    },
+    Custom(Arc<DistanceFn>),
+
+    // Combinations, blended over about `smoothness` where the surfaces meet
+    Union {
+        a: Arc<DistanceField>,
+        b: Arc<DistanceField>,
+        smoothness: f32,
+    },
+    Intersection {
+        a: Arc<DistanceField>,
+        b: Arc<DistanceField>,
+        smoothness: f32,
+    },
+    Difference {
+        a: Arc<DistanceField>,
+        b: Arc<DistanceField>,
+        smoothness: f32,
+    },
+
+    // Changes of space
+    Translate {
+        field: Arc<DistanceField>,
+        offset: Vec3,
+    },
+    // Rotation about the y axis by `rate` radians per unit up it
+    Twist {
+        field: Arc<DistanceField>,
+        rate: f32,
+    },
+    // Copies of the field every `period` along each axis, none along axes with a period of zero
+    Repeat {
+        field: Arc<DistanceField>,
+        period: Vec3,
+    },
+}
+
+impl DistanceField {
+    pub fn sphere(radius: f32) -> DistanceField {
+        DistanceField::Sphere { radius }
+    }
+
+    pub fn cuboid(half_size: Vec3, rounding: f32) -> DistanceField {
+        DistanceField::Cuboid {
+            half_size,
+            rounding,
+        }
+    }
+
+    pub fn torus(major_radius: f32, minor_radius: f32) -> DistanceField {
+        DistanceField::Torus {
+            major_radius,
+            minor_radius,
+        }
+    }
+
+    pub fn capsule(a: Point3, b: Point3, radius: f32) -> DistanceField {
+        DistanceField::Capsule { a, b, radius }
+    }
+
+    pub fn mandelbulb(power: f32, iterations: u32) -> DistanceField {
+        DistanceField::Mandelbulb { power, iterations }
+    }
+
+    pub fn custom(f: impl Fn(&Point3) -> f32 + Send + Sync + 'static) -> DistanceField {
+        DistanceField::Custom(Arc::new(f))
+    }
+
+    pub fn union(self, other: DistanceField, smoothness: f32) -> DistanceField {
+        DistanceField::Union {
+            a: Arc::new(self),
+            b: Arc::new(other),
+            smoothness,
+        }
+    }
+
+    pub fn intersection(self, other: DistanceField, smoothness: f32) -> DistanceField {
+        DistanceField::Intersection {
+            a: Arc::new(self),
+            b: Arc::new(other),
+            smoothness,
+        }
+    }
+
+    pub fn difference(self, other: DistanceField, smoothness: f32) -> DistanceField {
+        DistanceField::Difference {
+            a: Arc::new(self),
+            b: Arc::new(other),
+            smoothness,
+        }
+    }
+
+    pub fn translate(self, offset: Vec3) -> DistanceField {
+        DistanceField::Translate {
+            field: Arc::new(self),
+            offset,
+        }
+    }
+
+    pub fn twist(self, rate: f32) -> DistanceField {
+        DistanceField::Twist {
+            field: Arc::new(self),
+            rate,
+        }
+    }
+
+    pub fn repeat(self, period: Vec3) -> DistanceField {
+        DistanceField::Repeat {
+            field: Arc::new(self),
+            period,
+        }
+    }
+
+    pub fn distance(&self, p: &Point3) -> f32 {
+        // Signed distance to the surface, negative inside
+        match self {
+            DistanceField::Sphere { radius } => p.length() - radius,
+            DistanceField::Cuboid {
+                half_size,
+                rounding,
+            } => {
+                let q = Vec3::new(
+                    p.x().abs() - half_size.x() + rounding,
+                    p.y().abs() - half_size.y() + rounding,
+                    p.z().abs() - half_size.z() + rounding,
+                );
+                let outside = Vec3::new(q.x().max(0.0), q.y().max(0.0), q.z().max(0.0)).length();
+                let inside = q.x().max(q.y()).max(q.z()).min(0.0);
+                outside + inside - rounding
+            }
+            DistanceField::Torus {
+                major_radius,
+                minor_radius,
+            } => {
+                let across = (p.x() * p.x() + p.z() * p.z()).sqrt() - major_radius;
+                (across * across + p.y() * p.y()).sqrt() - minor_radius
+            }
+            DistanceField::Capsule { a, b, radius } => {
+                let pa = *p - *a;
+                let ba = *b - *a;
+                let h = (pa.dot(&ba) / ba.length_squared()).clamp(0.0, 1.0);
+                (pa - h * ba).length() - radius
+            }
+            DistanceField::Mandelbulb { power, iterations } => mandelbulb(p, *power, *iterations),
+            DistanceField::Custom(f) => f(p),
+            DistanceField::Union { a, b, smoothness } => {
+                smooth_min(a.distance(p), b.distance(p), *smoothness)
+            }
+            DistanceField::Intersection { a, b, smoothness } => {
+                -smooth_min(-a.distance(p), -b.distance(p), *smoothness)
+            }
+            DistanceField::Difference { a, b, smoothness } => {
+                -smooth_min(-a.distance(p), b.distance(p), *smoothness)
+            }
+            DistanceField::Translate { field, offset } => field.distance(&(*p - *offset)),
+            DistanceField::Twist { field, rate } => {
+                let (sin_theta, cos_theta) = (rate * p.y()).sin_cos();
+                let twisted = Point3::new(
+                    cos_theta * p.x() - sin_theta * p.z(),
+                    p.y(),
+                    sin_theta * p.x() + cos_theta * p.z(),
+                );
+
+                // Twisting stretches space further from the axis, so scale the distance down by
+                // how much to keep the steps from overshooting
+                let reach = rate * (p.x() * p.x() + p.z() * p.z()).sqrt();
+                field.distance(&twisted) / (1.0 + reach * reach).sqrt()
+            }
+            DistanceField::Repeat { field, period } => {
+                let mut q = *p;
+                for i in 0..3 {
+                    if period[i] > 0.0 {
+                        q[i] -= period[i] * (p[i] / period[i]).round();
+                    }
+                }
+                field.distance(&q)
+            }
+        }
+    }
+}
+
+fn smooth_min(a: f32, b: f32, k: f32) -> f32 {
+    // Polynomial smooth minimum, the plain minimum when k is zero
+    if k <= 0.0 {
+        return a.min(b);
+    }
+
+    let h = (0.5 + 0.5 * (b - a) / k).clamp(0.0, 1.0);
+    b + (a - b) * h - k * h * (1.0 - h)
+}
+
+fn mandelbulb(p: &Point3, power: f32, iterations: u32) -> f32 {
+    // Distance estimate from how fast the orbit of p escapes, following the running derivative
+    let mut z = *p;
+    let mut dr = 1.0;
+    let mut r = z.length();
+
+    for _ in 0..iterations {
+        if r > 2.0 {
+            break;
+        }
+
+        // Raise z to the power in spherical coordinates and add p back on
+        let theta = (z.z() / r).clamp(-1.0, 1.0).acos() * power;
+        let phi = z.y().atan2(z.x()) * power;
+        dr = r.powf(power - 1.0) * power * dr + 1.0;
+
+        let zr = r.powf(power);
+        z =
+            zr * Vec3::new(
+                theta.sin() * phi.cos(),
+                phi.sin() * theta.sin(),
+                theta.cos(),
+            ) + *p;
+        r = z.length();
+    }
+
+    0.5 * r.ln() * r / dr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combines_and_repeats_fields() {
+        let p = Point3::new(0.0, 0.0, 3.0);
+        assert!((DistanceField::sphere(1.0).distance(&p) - 2.0).abs() < 1e-6);
+        assert!(
+            (DistanceField::cuboid(Vec3::new(1.0, 1.0, 1.0), 0.0).distance(&p) - 2.0).abs() < 1e-6
+        );
+
+        // Blending only ever pulls the union in towards the point
+        let blob = DistanceField::sphere(1.0).union(
+            DistanceField::sphere(1.0).translate(Vec3::new(1.5, 0.0, 0.0)),
+            0.5,
+        );
+        let between = Point3::new(0.75, 0.9, 0.0);
+        assert!(blob.distance(&between) < DistanceField::sphere(1.0).distance(&between));
+
+        // Every copy is the same distance away
+        let row = DistanceField::sphere(0.5).repeat(Vec3::new(2.0, 0.0, 0.0));
+        let near = row.distance(&Point3::new(0.3, 1.0, 0.0));
+        let far = row.distance(&Point3::new(10.3, 1.0, 0.0));
+        assert!((near - far).abs() < 1e-5);
+    }
+}
@@ -5,7 +5,9 @@ use crate::{
     hittable::{HitRecord, Hittable},
     interval::Interval,
     material::MaterialType,
+    onb::Onb,
     ray::Ray,
+    utility::random_float,
     vec3::{Point3, Vec3},
 };
 
@@ -47,6 +49,14 @@ impl Sphere {
         }
     }
 
+    pub fn material(&self) -> &Arc<MaterialType> {
+        &self.mat
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
     pub fn get_sphere_uv(&self, p: &Point3, u: &mut f32, v: &mut f32) {
         // p: a point on the sphere of radius one, centered at the origin
         // u: returned value [0, 1] of angle around the Y axis from X=-1
@@ -63,9 +73,26 @@ impl Sphere {
     }
 }
 
+impl Sphere {
+    fn random_to_sphere(radius: f32, distance_squared: f32) -> Vec3 {
+        // Uniform random direction within the cone the sphere subtends, around the z axis
+        let r1 = random_float();
+        let r2 = random_float();
+        let z = 1.0 + r2 * ((1.0 - radius * radius / distance_squared).sqrt() - 1.0);
+
+        let phi = 2.0 * PI * r1;
+        let x = phi.cos() * (1.0 - z * z).sqrt();
+        let y = phi.sin() * (1.0 - z * z).sqrt();
+
+        Vec3::new(x, y, z)
+    }
+}
+
 impl Hittable for Sphere {
     fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
-        let current_center = self.center.at(ray.time());
+        // A moving sphere travels from its first center at time 0 to its second at time 1, and
+        // rests there outside that span so a shutter open for longer stays within its bounds
+        let current_center = self.center.at(ray.time().clamp(0.0, 1.0));
         let oc: Vec3 = current_center - ray.origin();
         let a = ray.direction().length_squared();
         let h = ray.direction().dot(&oc);
@@ -100,4 +127,37 @@ impl Hittable for Sphere {
     fn bounding_box(&self) -> &Aabb {
         &self.bbox
     }
+
+    fn pdf_value(&self, origin: &Point3, direction: &Vec3) -> f32 {
+        // This method only works for stationary spheres
+        let mut rec = HitRecord::default();
+        if !self.hit(
+            &Ray::new(origin, direction, 0.0),
+            &Interval::new(0.001, f32::INFINITY),
+            &mut rec,
+        ) {
+            return 0.0;
+        }
+
+        let distance_squared = (self.center.at(0.0) - origin).length_squared();
+        if distance_squared <= self.radius * self.radius {
+            return 1.0 / (4.0 * PI);
+        }
+
+        let cos_theta_max = (1.0 - self.radius * self.radius / distance_squared).sqrt();
+        let solid_angle = 2.0 * PI * (1.0 - cos_theta_max);
+
+        1.0 / solid_angle
+    }
+
+    fn random(&self, origin: &Point3) -> Vec3 {
+        let direction = self.center.at(0.0) - origin;
+        let distance_squared = direction.length_squared();
+        if distance_squared <= self.radius * self.radius {
+            return Vec3::random_unit_vector();
+        }
+
+        let uvw = Onb::new(&direction);
+        uvw.transform(&Self::random_to_sphere(self.radius, distance_squared))
+    }
 }
@@ -0,0 +1,292 @@
+use std::{f64::consts::PI, sync::Arc};
+
+use crate::{
+    aabb::Aabb,
+    interval::Interval,
+    material::MaterialType,
+    onb::Onb,
+    ray::Ray,
+    utility::turn_fraction,
+    vec3::{Point3, Vec3},
+};
+
+use super::{HitRecord, Hittable};
+
+// Magnitude below which polynomial terms are treated as zero
+const EQN_EPS: f64 = 1e-9;
+
+#[derive(Clone)]
+pub struct Torus {
+    // Center of the ring, which goes around w of the frame
+    center: Point3,
+    frame: Onb,
+    // Radius of the ring through the middle of the tube, and of the tube around it
+    major_radius: f32,
+    minor_radius: f32,
+    mat: Arc<MaterialType>,
+    bbox: Aabb,
+}
+
+impl Torus {
+    pub fn new(
+        center: Point3,
+        axis: Vec3,
+        major_radius: f32,
+        minor_radius: f32,
+        mat: MaterialType,
+    ) -> Self {
+        let minor_radius = minor_radius.max(0.0);
+        let major_radius = major_radius.max(minor_radius);
+
+        // The ring through the middle of the tube, grown by the tube all round
+        let ring = Aabb::from_circle(&center, &axis, major_radius);
+        let bbox = Aabb::new(
+            ring.x.expands(2.0 * minor_radius),
+            ring.y.expands(2.0 * minor_radius),
+            ring.z.expands(2.0 * minor_radius),
+        );
+
+        Self {
+            center,
+            frame: Onb::new(&axis),
+            major_radius,
+            minor_radius,
+            mat: Arc::new(mat),
+            bbox,
+        }
+    }
+}
+
+impl Hittable for Torus {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        // Intersect in the frame of the torus with a unit direction, starting from the point
+        // nearest the center to keep the quartic well conditioned
+        let length = ray.direction().length() as f64;
+        let o = self.frame.untransform(&(*ray.origin() - self.center));
+        let d = self.frame.untransform(ray.direction()) / length as f32;
+        let (o, d) = (
+            [o.x() as f64, o.y() as f64, o.z() as f64],
+            [d.x() as f64, d.y() as f64, d.z() as f64],
+        );
+
+        let shift = -(o[0] * d[0] + o[1] * d[1] + o[2] * d[2]);
+        let o = [
+            o[0] + shift * d[0],
+            o[1] + shift * d[1],
+            o[2] + shift * d[2],
+        ];
+
+        // (|p|^2 + R^2 - r^2)^2 = 4 R^2 (x^2 + y^2) along the ray, with |d| = 1
+        let big_r2 = (self.major_radius as f64).powi(2);
+        let small_r2 = (self.minor_radius as f64).powi(2);
+        let e = o[0] * o[0] + o[1] * o[1] + o[2] * o[2] - big_r2 - small_r2;
+        let f = o[0] * d[0] + o[1] * d[1] + o[2] * d[2];
+        let four_r2 = 4.0 * big_r2;
+
+        let coefficients = [
+            e * e - four_r2 * (small_r2 - o[2] * o[2]),
+            4.0 * f * e + 2.0 * four_r2 * o[2] * d[2],
+            2.0 * e + 4.0 * f * f + four_r2 * d[2] * d[2],
+            4.0 * f,
+            1.0,
+        ];
+
+        let Some(t) = solve_quartic(&coefficients)
+            .into_iter()
+            .map(|root| ((polish_root(&coefficients, root) + shift) / length) as f32)
+            .filter(|t| ray_t.surrounds(*t))
+            .min_by(f32::total_cmp)
+        else {
+            return false;
+        };
+
+        // The normal points away from the nearest point of the ring through the tube
+        let p = ray.at(t);
+        let local = self.frame.untransform(&(p - self.center));
+        let across = (local.x() * local.x() + local.y() * local.y()).sqrt();
+        let ring = if across > 0.0 {
+            Vec3::new(local.x(), local.y(), 0.0) * (self.major_radius / across)
+        } else {
+            Vec3::default()
+        };
+        let local_normal = (local - ring).unit_vector();
+
+        // u goes around the ring and v around the tube, from its outer edge
+        hit_record.u = turn_fraction(local.x(), local.y());
+        hit_record.v = turn_fraction(across - self.major_radius, local.z());
+
+        hit_record.t = t;
+        hit_record.p = p;
+        hit_record.mat = self.mat.clone();
+        hit_record.set_face_normal(ray, &self.frame.transform(&local_normal));
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
+
+fn is_zero(x: f64) -> bool {
+    x.abs() < EQN_EPS
+}
+
+fn solve_quadratic(c: &[f64; 3]) -> Vec<f64> {
+    // Real roots of c[2] x^2 + c[1] x + c[0]
+    let p = c[1] / (2.0 * c[2]);
+    let q = c[0] / c[2];
+    let discriminant = p * p - q;
+
+    if is_zero(discriminant) {
+        vec![-p]
+    } else if discriminant < 0.0 {
+        vec![]
+    } else {
+        let sqrt_d = discriminant.sqrt();
+        vec![sqrt_d - p, -sqrt_d - p]
+    }
+}
+
+fn solve_cubic(c: &[f64; 4]) -> Vec<f64> {
+    // Real roots of c[3] x^3 + c[2] x^2 + c[1] x + c[0], by Cardano's method on the depressed
+    // cubic x^3 + p x + q
+    let a = c[2] / c[3];
+    let b = c[1] / c[3];
+    let cc = c[0] / c[3];
+
+    let sq_a = a * a;
+    let p = (-sq_a / 3.0 + b) / 3.0;
+    let q = (2.0 / 27.0 * a * sq_a - a * b / 3.0 + cc) / 2.0;
+
+    let cb_p = p * p * p;
+    let discriminant = q * q + cb_p;
+
+    let roots = if is_zero(discriminant) {
+        if is_zero(q) {
+            vec![0.0]
+        } else {
+            let u = (-q).cbrt();
+            vec![2.0 * u, -u]
+        }
+    } else if discriminant < 0.0 {
+        // Three real roots
+        let phi = (-q / (-cb_p).sqrt()).clamp(-1.0, 1.0).acos() / 3.0;
+        let t = 2.0 * (-p).sqrt();
+        vec![
+            t * phi.cos(),
+            -t * (phi + PI / 3.0).cos(),
+            -t * (phi - PI / 3.0).cos(),
+        ]
+    } else {
+        let sqrt_d = discriminant.sqrt();
+        vec![(sqrt_d - q).cbrt() - (sqrt_d + q).cbrt()]
+    };
+
+    roots.into_iter().map(|root| root - a / 3.0).collect()
+}
+
+fn solve_quartic(c: &[f64; 5]) -> Vec<f64> {
+    // Real roots of c[4] x^4 + ... + c[0], by Ferrari's method on the depressed quartic
+    // x^4 + p x^2 + q x + r
+    let a = c[3] / c[4];
+    let b = c[2] / c[4];
+    let cc = c[1] / c[4];
+    let d = c[0] / c[4];
+
+    let sq_a = a * a;
+    let p = -3.0 / 8.0 * sq_a + b;
+    let q = sq_a * a / 8.0 - a * b / 2.0 + cc;
+    let r = -3.0 / 256.0 * sq_a * sq_a + sq_a * b / 16.0 - a * cc / 4.0 + d;
+
+    let roots = if is_zero(r) {
+        // No constant term, so x (x^3 + p x + q) = 0
+        let mut roots = solve_cubic(&[q, p, 0.0, 1.0]);
+        roots.push(0.0);
+        roots
+    } else {
+        // Split into two quadratics using a root of the resolvent cubic
+        let z = solve_cubic(&[r * p / 2.0 - q * q / 8.0, -r, -p / 2.0, 1.0])[0];
+
+        let u = z * z - r;
+        let v = 2.0 * z - p;
+        let u = if is_zero(u) {
+            0.0
+        } else if u > 0.0 {
+            u.sqrt()
+        } else {
+            return vec![];
+        };
+        let v = if is_zero(v) {
+            0.0
+        } else if v > 0.0 {
+            v.sqrt()
+        } else {
+            return vec![];
+        };
+
+        let v = if q < 0.0 { -v } else { v };
+        let mut roots = solve_quadratic(&[z - u, v, 1.0]);
+        roots.extend(solve_quadratic(&[z + u, -v, 1.0]));
+        roots
+    };
+
+    roots.into_iter().map(|root| root - a / 4.0).collect()
+}
+
+fn polish_root(c: &[f64; 5], root: f64) -> f64 {
+    // A couple of Newton steps, to win back precision lost in the closed form
+    let mut x = root;
+    for _ in 0..2 {
+        let value = (((c[4] * x + c[3]) * x + c[2]) * x + c[1]) * x + c[0];
+        let slope = ((4.0 * c[4] * x + 3.0 * c[3]) * x + 2.0 * c[2]) * x + c[1];
+        if slope.abs() < EQN_EPS {
+            break;
+        }
+        x -= value / slope;
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_quartics() {
+        // (x + 1)(x - 1)(x - 2)(x - 3)
+        let mut roots = solve_quartic(&[-6.0, 5.0, 5.0, -5.0, 1.0]);
+        roots.sort_by(f64::total_cmp);
+        for (root, expected) in roots.iter().zip([-1.0, 1.0, 2.0, 3.0]) {
+            assert!((root - expected).abs() < 1e-6, "{roots:?}");
+        }
+        assert_eq!(roots.len(), 4);
+
+        // x^4 + 1 has no real roots
+        assert!(solve_quartic(&[1.0, 0.0, 0.0, 0.0, 1.0]).is_empty());
+    }
+
+    #[test]
+    fn hits_the_tube_from_outside_and_through_the_hole() {
+        let torus = Torus::new(
+            Point3::default(),
+            Vec3::new(0.0, 1.0, 0.0),
+            2.0,
+            0.5,
+            MaterialType::None,
+        );
+        let t_range = Interval::new(0.001, f32::INFINITY);
+
+        // Along x from outside, meeting the outer edge of the tube first
+        let ray = Ray::new(&Point3::new(5.0, 0.0, 0.0), &Vec3::new(-2.0, 0.0, 0.0), 0.0);
+        let mut rec = HitRecord::default();
+        assert!(torus.hit(&ray, &t_range, &mut rec));
+        assert!((rec.p.x() - 2.5).abs() < 1e-4);
+        assert!((rec.normal.x() - 1.0).abs() < 1e-4);
+
+        // Straight down the hole
+        let ray = Ray::new(&Point3::new(0.0, 5.0, 0.0), &Vec3::new(0.0, -1.0, 0.0), 0.0);
+        assert!(!torus.hit(&ray, &t_range, &mut rec));
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::{aabb::Aabb, interval::Interval, mat4::Mat4, ray::Ray, vec3::Point3};
+
+use super::{HitRecord, Hittable, HittableObject};
+
+#[derive(Clone)]
+pub struct Transform {
+    object: Arc<HittableObject>,
+    // Moves the object from its own space into the world, and back
+    matrix: Mat4,
+    inverse: Mat4,
+    bbox: Aabb,
+}
+
+impl Transform {
+    pub fn new(object: HittableObject, matrix: Mat4) -> Self {
+        // Transforming a transform again folds the two into one matrix
+        match object {
+            HittableObject::Transform(inner) => Self::shared(inner.object, matrix * inner.matrix),
+            _ => Self::shared(Arc::new(object), matrix),
+        }
+    }
+
+    pub fn shared(object: Arc<HittableObject>, matrix: Mat4) -> Self {
+        // Transform of an object that may be placed elsewhere by other transforms too
+        let inverse = matrix
+            .inverse()
+            .expect("a transform must not flatten the object");
+
+        let bbox = *object.bounding_box();
+        let mut min = Point3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+        let mut max = Point3::new(-f32::INFINITY, -f32::INFINITY, -f32::INFINITY);
+
+        for i in 0..2 {
+            for j in 0..2 {
+                for k in 0..2 {
+                    let x = i as f32 * bbox.x.max + (1 - i) as f32 * bbox.x.min;
+                    let y = j as f32 * bbox.y.max + (1 - j) as f32 * bbox.y.min;
+                    let z = k as f32 * bbox.z.max + (1 - k) as f32 * bbox.z.min;
+
+                    let tester = matrix.transform_point(&Point3::new(x, y, z));
+
+                    for c in 0..3 {
+                        min[c] = min[c].min(tester[c]);
+                        max[c] = max[c].max(tester[c]);
+                    }
+                }
+            }
+        }
+
+        let bbox = Aabb::from_points(&min, &max);
+
+        Self {
+            object,
+            matrix,
+            inverse,
+            bbox,
+        }
+    }
+}
+
+impl Hittable for Transform {
+    fn hit(&self, ray: &Ray, ray_t: &Interval, hit_record: &mut HitRecord) -> bool {
+        // Transform the ray from world to object space. The direction is not normalized so
+        // distances along the ray stay the same in both spaces.
+        let object_r = Ray::new(
+            &self.inverse.transform_point(ray.origin()),
+            &self.inverse.transform_vector(ray.direction()),
+            ray.time(),
+        );
+
+        // Determine whether an intersection exists in object space (and if so, where).
+        if !self.object.hit(&object_r, ray_t, hit_record) {
+            return false;
+        }
+
+        // Transform the intersection from object space to world space.
+        hit_record.p = self.matrix.transform_point(&hit_record.p);
+        hit_record.normal = self
+            .inverse
+            .transform_normal(&hit_record.normal)
+            .unit_vector();
+
+        true
+    }
+
+    fn bounding_box(&self) -> &Aabb {
+        &self.bbox
+    }
+}
@@ -0,0 +1,134 @@
+use crate::color::Color;
+
+// Deepest nesting of dielectrics tracked along a single path
+const MAX_NESTING: usize = 8;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Interior {
+    // Refractive index of the medium filling the object
+    pub refractive_index: f32,
+    // Where media overlap, the one with the highest priority owns the volume
+    pub priority: i32,
+    // Fraction of light absorbed per unit distance travelled through the medium
+    pub absorption: Color,
+}
+
+impl Interior {
+    pub const AIR: Interior = Interior {
+        refractive_index: 1.0,
+        priority: i32::MIN,
+        absorption: Color::new(0.0, 0.0, 0.0),
+    };
+
+    pub fn is_same_medium(&self, other: &Interior) -> bool {
+        self.refractive_index == other.refractive_index
+            && self.priority == other.priority
+            && self.absorption.x() == other.absorption.x()
+            && self.absorption.y() == other.absorption.y()
+            && self.absorption.z() == other.absorption.z()
+    }
+
+    pub fn transmittance(&self, distance: f32) -> Color {
+        // Beer-Lambert attenuation over a straight path of the given length
+        Color::new(
+            (-self.absorption.x() * distance).exp(),
+            (-self.absorption.y() * distance).exp(),
+            (-self.absorption.z() * distance).exp(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct InteriorStack {
+    entries: [Interior; MAX_NESTING],
+    len: usize,
+}
+
+impl InteriorStack {
+    pub fn current(&self) -> &Interior {
+        // The medium a ray is currently travelling through is the highest priority interior it
+        // is inside of, favouring the most recently entered one on ties.
+        let mut current = &Interior::AIR;
+        for interior in &self.entries[..self.len] {
+            if interior.priority >= current.priority {
+                current = interior;
+            }
+        }
+
+        current
+    }
+
+    pub fn is_boundary(&self, interior: &Interior) -> bool {
+        // A surface only changes the medium if nothing of higher priority already fills the space
+        // on this side of it, otherwise it is a false intersection the ray passes straight through.
+        interior.priority >= self.current().priority
+    }
+
+    pub fn entered(&self, interior: &Interior) -> InteriorStack {
+        let mut tmp = *self;
+        if tmp.len < MAX_NESTING {
+            tmp.entries[tmp.len] = *interior;
+            tmp.len += 1;
+        }
+
+        tmp
+    }
+
+    pub fn exited(&self, interior: &Interior) -> InteriorStack {
+        let mut tmp = *self;
+        if let Some(index) = tmp.entries[..tmp.len]
+            .iter()
+            .rposition(|entry| entry.is_same_medium(interior))
+        {
+            tmp.entries.copy_within(index + 1..tmp.len, index);
+            tmp.len -= 1;
+        }
+
+        tmp
+    }
+}
+
+impl Default for InteriorStack {
+    fn default() -> Self {
+        Self {
+            entries: [Interior::AIR; MAX_NESTING],
+            len: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn medium(refractive_index: f32, priority: i32) -> Interior {
+        Interior {
+            refractive_index,
+            priority,
+            absorption: Color::default(),
+        }
+    }
+
+    #[test]
+    fn empty_stack_is_air() {
+        let stack = InteriorStack::default();
+        assert_eq!(stack.current().refractive_index, 1.0);
+        assert!(stack.is_boundary(&medium(1.5, 0)));
+    }
+
+    #[test]
+    fn lower_priority_surfaces_are_skipped() {
+        let glass = medium(1.5, 2);
+        let water = medium(1.33, 1);
+
+        let in_glass = InteriorStack::default().entered(&glass);
+        assert!(!in_glass.is_boundary(&water));
+
+        let in_both = in_glass.entered(&water);
+        assert_eq!(in_both.current().refractive_index, 1.5);
+
+        let in_water = in_both.exited(&glass);
+        assert_eq!(in_water.current().refractive_index, 1.33);
+        assert!(in_water.exited(&water).is_boundary(&water));
+    }
+}
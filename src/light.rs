@@ -0,0 +1,139 @@
+use crate::{
+    color::Color,
+    hittable::HittableObject,
+    vec3::{Point3, Vec3},
+};
+
+pub mod area;
+pub mod bvh;
+pub mod directional;
+pub mod ies;
+pub mod point;
+pub mod spot;
+
+use area::AreaLight;
+use bvh::LightBounds;
+use directional::DirectionalLight;
+use ies::IesProfile;
+use point::{Falloff, PointLight};
+use spot::SpotLight;
+
+pub struct LightSample {
+    // Unit direction from the shading point towards the light
+    pub direction: Vec3,
+    // Distance to the light along the direction, infinite for distant lights
+    pub distance: f32,
+    // Radiance arriving at the shading point when nothing blocks the light
+    pub radiance: Color,
+}
+
+pub trait Light {
+    fn sample(&self, _p: &Point3) -> Option<LightSample> {
+        None
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        // Where the light is and how much it emits, None for lights infinitely far away
+        None
+    }
+}
+
+#[derive(Clone)]
+pub enum LightType {
+    Point(PointLight),
+    Spot(SpotLight),
+    Directional(DirectionalLight),
+    Area(AreaLight),
+}
+
+impl LightType {
+    pub fn point(position: Point3, intensity: Color, falloff: Falloff) -> LightType {
+        LightType::Point(PointLight::new(position, intensity, falloff))
+    }
+
+    pub fn spot(
+        position: Point3,
+        look_at: Point3,
+        intensity: Color,
+        inner_angle: f32,
+        outer_angle: f32,
+    ) -> LightType {
+        LightType::Spot(SpotLight::new(
+            position,
+            look_at - position,
+            intensity,
+            inner_angle,
+            outer_angle,
+        ))
+    }
+
+    pub fn ies_point(
+        position: Point3,
+        look_at: Point3,
+        c0: Vec3,
+        intensity: Color,
+        profile: IesProfile,
+    ) -> LightType {
+        // The profile's nadir points at `look_at` and its zero horizontal angle towards `c0`
+        LightType::Point(
+            PointLight::new(position, intensity, Falloff::InverseSquare).with_profile(
+                profile,
+                look_at - position,
+                c0,
+            ),
+        )
+    }
+
+    pub fn ies_spot(
+        position: Point3,
+        look_at: Point3,
+        c0: Vec3,
+        intensity: Color,
+        inner_angle: f32,
+        outer_angle: f32,
+        profile: IesProfile,
+    ) -> LightType {
+        LightType::Spot(
+            SpotLight::new(
+                position,
+                look_at - position,
+                intensity,
+                inner_angle,
+                outer_angle,
+            )
+            .with_profile(profile, c0),
+        )
+    }
+
+    pub fn directional(direction: Vec3, irradiance: Color, angular_radius: f32) -> LightType {
+        LightType::Directional(DirectionalLight::new(direction, irradiance, angular_radius))
+    }
+
+    pub fn area(object: &HittableObject) -> Option<LightType> {
+        // Sample an emissive quad, planar shape or sphere of the scene directly, the object must
+        // also be added to the world
+        AreaLight::new(object).map(LightType::Area)
+    }
+
+    pub fn sample(&self, p: &Point3) -> Option<LightSample> {
+        match self {
+            LightType::Point(light) => light.sample(p),
+            LightType::Spot(light) => light.sample(p),
+            LightType::Directional(light) => light.sample(p),
+            LightType::Area(light) => light.sample(p),
+        }
+    }
+
+    pub fn bounds(&self) -> Option<LightBounds> {
+        match self {
+            LightType::Point(light) => light.bounds(),
+            LightType::Spot(light) => light.bounds(),
+            LightType::Directional(light) => light.bounds(),
+            LightType::Area(light) => light.bounds(),
+        }
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        matches!(self, LightType::Directional(_))
+    }
+}
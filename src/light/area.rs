@@ -0,0 +1,92 @@
+use std::{f32::consts::PI, sync::Arc};
+
+use crate::{
+    hittable::HittableObject,
+    material::MaterialType,
+    vec3::{Point3, Vec3},
+};
+
+use super::{bvh::LightBounds, Light};
+
+#[derive(Clone)]
+pub struct AreaLight {
+    // Emissive surface in the scene, sampled towards the shading point
+    object: Arc<HittableObject>,
+    // Material shared with the surface in the scene, to recognise rays that hit it
+    mat: Arc<MaterialType>,
+}
+
+impl AreaLight {
+    pub fn new(object: &HittableObject) -> Option<Self> {
+        // Only single quads, other planar shapes and spheres with a diffuse light material can be
+        // sampled
+        match object {
+            HittableObject::Quad(_) | HittableObject::Sphere(_) => (),
+            _ => return None,
+        }
+
+        let mat = object.material()?.clone();
+        if !matches!(mat.as_ref(), MaterialType::DiffuseLight(_)) {
+            return None;
+        }
+
+        Some(Self {
+            object: Arc::new(object.clone()),
+            mat,
+        })
+    }
+
+    pub fn material(&self) -> &Arc<MaterialType> {
+        &self.mat
+    }
+
+    pub fn is_emitter(&self, mat: &Arc<MaterialType>) -> bool {
+        Arc::ptr_eq(&self.mat, mat)
+    }
+
+    pub fn random(&self, origin: &Point3) -> Vec3 {
+        self.object.random(origin)
+    }
+
+    pub fn pdf_value(&self, origin: &Point3, direction: &Vec3) -> f32 {
+        self.object.pdf_value(origin, direction)
+    }
+}
+
+impl Light for AreaLight {
+    fn bounds(&self) -> Option<LightBounds> {
+        let MaterialType::DiffuseLight(light) = self.mat.as_ref() else {
+            return None;
+        };
+
+        let bbox = *self.object.bounding_box();
+        let radiance = light.radiance_estimate(&bbox.centroid()).luminance();
+
+        // Emission of a Lambertian emitter is radiance times pi over every unit of area
+        match self.object.as_ref() {
+            HittableObject::Quad(quad) => {
+                let sides = if light.is_one_sided() { 1.0 } else { 2.0 };
+                Some(LightBounds::new(
+                    bbox,
+                    sides * PI * quad.area() * radiance,
+                    *quad.normal(),
+                    1.0,
+                    0.0,
+                    !light.is_one_sided(),
+                ))
+            }
+            HittableObject::Sphere(sphere) => {
+                let area = 4.0 * PI * sphere.radius() * sphere.radius();
+                Some(LightBounds::new(
+                    bbox,
+                    PI * area * radiance,
+                    Vec3::new(0.0, 0.0, 1.0),
+                    -1.0,
+                    0.0,
+                    false,
+                ))
+            }
+            _ => None,
+        }
+    }
+}
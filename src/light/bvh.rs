@@ -0,0 +1,416 @@
+use std::{collections::HashMap, f32::consts::PI, sync::Arc};
+
+use crate::{
+    aabb::Aabb,
+    material::MaterialType,
+    utility::random_float,
+    vec3::{Point3, Vec3},
+};
+
+use super::LightType;
+
+#[derive(Debug, Clone, Copy)]
+pub struct LightBounds {
+    // Bounds of every point the light emits from
+    bbox: Aabb,
+    // Total emitted power, the estimate of how much the light contributes
+    phi: f32,
+    // Unit axis of the cone bounding the directions the light faces
+    w: Vec3,
+    // Cosine of the spread of those directions around the axis
+    cos_theta_o: f32,
+    // Cosine of the angle past the facing directions beyond which nothing is emitted
+    cos_theta_e: f32,
+    // Light leaves both sides of the surface
+    two_sided: bool,
+}
+
+impl LightBounds {
+    pub fn new(
+        bbox: Aabb,
+        phi: f32,
+        w: Vec3,
+        cos_theta_o: f32,
+        cos_theta_e: f32,
+        two_sided: bool,
+    ) -> Self {
+        Self {
+            bbox,
+            phi,
+            w: w.unit_vector(),
+            cos_theta_o,
+            cos_theta_e,
+            two_sided,
+        }
+    }
+
+    pub fn from_bounds(a: &LightBounds, b: &LightBounds) -> LightBounds {
+        if a.phi <= 0.0 {
+            return *b;
+        }
+        if b.phi <= 0.0 {
+            return *a;
+        }
+
+        let (w, cos_theta_o) = cone_union(&a.w, a.cos_theta_o, &b.w, b.cos_theta_o);
+
+        LightBounds {
+            bbox: Aabb::from_aabbs(&a.bbox, &b.bbox),
+            phi: a.phi + b.phi,
+            w,
+            cos_theta_o,
+            cos_theta_e: a.cos_theta_e.min(b.cos_theta_e),
+            two_sided: a.two_sided || b.two_sided,
+        }
+    }
+
+    pub fn importance(&self, p: &Point3, n: &Vec3) -> f32 {
+        // Conservative estimate of the light reaching `p` from anywhere in the bounds, a zero
+        // normal is used for points in a participating medium
+        let pc = self.bbox.centroid();
+        let to_point = *p - pc;
+        let distance_squared = to_point.length_squared();
+
+        // Clamp the distance so points inside the bounds don't get unbounded importance
+        let diagonal = Vec3::new(self.bbox.x.size(), self.bbox.y.size(), self.bbox.z.size());
+        let radius = diagonal.length() / 2.0;
+        let d2 = distance_squared.max(radius);
+
+        let wi = if distance_squared > 0.0 {
+            to_point / distance_squared.sqrt()
+        } else {
+            self.w
+        };
+
+        // Angle between the axis of the cone and the point
+        let mut cos_theta_w = self.w.dot(&wi);
+        if self.two_sided {
+            cos_theta_w = cos_theta_w.abs();
+        }
+        let sin_theta_w = safe_sqrt(1.0 - cos_theta_w * cos_theta_w);
+
+        // Angle subtended by the bounds as seen from the point
+        let cos_theta_b = if distance_squared < radius * radius {
+            -1.0
+        } else {
+            safe_sqrt(1.0 - radius * radius / distance_squared)
+        };
+        let sin_theta_b = safe_sqrt(1.0 - cos_theta_b * cos_theta_b);
+
+        // Smallest angle between the point and any direction the light faces
+        let sin_theta_o = safe_sqrt(1.0 - self.cos_theta_o * self.cos_theta_o);
+        let cos_theta_x = cos_sub_clamped(sin_theta_w, cos_theta_w, sin_theta_o, self.cos_theta_o);
+        let sin_theta_x = sin_sub_clamped(sin_theta_w, cos_theta_w, sin_theta_o, self.cos_theta_o);
+        let cos_theta_p = cos_sub_clamped(sin_theta_x, cos_theta_x, sin_theta_b, cos_theta_b);
+        if cos_theta_p <= self.cos_theta_e {
+            return 0.0;
+        }
+
+        let mut importance = self.phi * cos_theta_p / d2;
+
+        // Light arriving at a surface is scaled by the cosine with its normal
+        if n.length_squared() > 0.0 {
+            let cos_theta_i = wi.dot(n).abs();
+            let sin_theta_i = safe_sqrt(1.0 - cos_theta_i * cos_theta_i);
+            importance *= cos_sub_clamped(sin_theta_i, cos_theta_i, sin_theta_b, cos_theta_b);
+        }
+
+        importance.max(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LightBvhNode {
+    bounds: LightBounds,
+    // The light of a leaf, or the second child of an interior node whose first child directly
+    // follows it
+    index: usize,
+    is_leaf: bool,
+}
+
+#[derive(Default)]
+pub struct LightBvh {
+    nodes: Vec<LightBvhNode>,
+    // Branches taken from the root to each light, one bit per level set for the second child
+    trails: HashMap<usize, u64>,
+    // Lights that are surfaces in the scene, by the address of their material
+    emitters: HashMap<usize, usize>,
+}
+
+impl LightBvh {
+    pub fn new(lights: &[LightType]) -> Self {
+        // Lights without bounds are infinitely far away and are sampled separately
+        let mut bounded: Vec<_> = lights
+            .iter()
+            .enumerate()
+            .filter_map(|(index, light)| light.bounds().map(|bounds| (index, bounds)))
+            .filter(|(_, bounds)| bounds.phi > 0.0)
+            .collect();
+
+        let mut tmp = Self::default();
+        if !bounded.is_empty() {
+            tmp.build(&mut bounded, 0, 0);
+        }
+
+        for (index, light) in lights.iter().enumerate() {
+            if let LightType::Area(light) = light {
+                if tmp.trails.contains_key(&index) {
+                    tmp.emitters
+                        .insert(Arc::as_ptr(light.material()) as usize, index);
+                }
+            }
+        }
+
+        tmp
+    }
+
+    pub fn sample(&self, p: &Point3, n: &Vec3) -> Option<(usize, f32)> {
+        // Pick a light proportional to its estimated contribution at `p`, returning its index
+        // and the probability it was picked with
+        if self.nodes.is_empty() {
+            return None;
+        }
+
+        let mut node_index = 0;
+        let mut pmf = 1.0;
+        loop {
+            let node = &self.nodes[node_index];
+            if node.is_leaf {
+                return (node.bounds.importance(p, n) > 0.0).then_some((node.index, pmf));
+            }
+
+            let first = self.nodes[node_index + 1].bounds.importance(p, n);
+            let second = self.nodes[node.index].bounds.importance(p, n);
+            if first + second <= 0.0 {
+                return None;
+            }
+
+            let p_first = first / (first + second);
+            if random_float() < p_first {
+                node_index += 1;
+                pmf *= p_first;
+            } else {
+                node_index = node.index;
+                pmf *= 1.0 - p_first;
+            }
+        }
+    }
+
+    pub fn pmf(&self, p: &Point3, n: &Vec3, light: usize) -> f32 {
+        // Probability `sample` picks the light at `p`
+        let Some(mut trail) = self.trails.get(&light).copied() else {
+            return 0.0;
+        };
+
+        let mut node_index = 0;
+        let mut pmf = 1.0;
+        loop {
+            let node = &self.nodes[node_index];
+            if node.is_leaf {
+                return if node.bounds.importance(p, n) > 0.0 {
+                    pmf
+                } else {
+                    0.0
+                };
+            }
+
+            let first = self.nodes[node_index + 1].bounds.importance(p, n);
+            let second = self.nodes[node.index].bounds.importance(p, n);
+            if first + second <= 0.0 {
+                return 0.0;
+            }
+
+            if trail & 1 == 0 {
+                node_index += 1;
+                pmf *= first / (first + second);
+            } else {
+                node_index = node.index;
+                pmf *= second / (first + second);
+            }
+            trail >>= 1;
+        }
+    }
+
+    pub fn emitter(&self, mat: &Arc<MaterialType>) -> Option<usize> {
+        // Index of the light whose surface uses the material
+        self.emitters.get(&(Arc::as_ptr(mat) as usize)).copied()
+    }
+
+    fn build(&mut self, lights: &mut [(usize, LightBounds)], trail: u64, depth: u32) -> usize {
+        let node_index = self.nodes.len();
+
+        if let [(light, bounds)] = lights {
+            self.nodes.push(LightBvhNode {
+                bounds: *bounds,
+                index: *light,
+                is_leaf: true,
+            });
+            self.trails.insert(*light, trail);
+            return node_index;
+        }
+
+        // Split the lights in half along the longest axis of their centers
+        let mut bounds = lights[0].1;
+        for (_, light_bounds) in &lights[1..] {
+            bounds = LightBounds::from_bounds(&bounds, light_bounds);
+        }
+
+        let mut centroids = Aabb::EMPTY;
+        for (_, light_bounds) in lights.iter() {
+            let centroid = light_bounds.bbox.centroid();
+            centroids = Aabb::from_aabbs(&centroids, &Aabb::from_points(&centroid, &centroid));
+        }
+
+        let axis = centroids.longest_axis();
+        lights.sort_by(|a, b| a.1.bbox.centroid()[axis].total_cmp(&b.1.bbox.centroid()[axis]));
+
+        self.nodes.push(LightBvhNode {
+            bounds,
+            index: 0,
+            is_leaf: false,
+        });
+
+        let mid = lights.len() / 2;
+        let (first, second) = lights.split_at_mut(mid);
+        self.build(first, trail, depth + 1);
+        self.nodes[node_index].index = self.build(second, trail | (1 << depth), depth + 1);
+
+        node_index
+    }
+}
+
+fn safe_sqrt(x: f32) -> f32 {
+    x.max(0.0).sqrt()
+}
+
+fn cos_sub_clamped(sin_a: f32, cos_a: f32, sin_b: f32, cos_b: f32) -> f32 {
+    // Cosine of the angle a - b, or one when b is larger than a
+    if cos_a > cos_b {
+        1.0
+    } else {
+        cos_a * cos_b + sin_a * sin_b
+    }
+}
+
+fn sin_sub_clamped(sin_a: f32, cos_a: f32, sin_b: f32, cos_b: f32) -> f32 {
+    // Sine of the angle a - b, or zero when b is larger than a
+    if cos_a > cos_b {
+        0.0
+    } else {
+        sin_a * cos_b - cos_a * sin_b
+    }
+}
+
+fn cone_union(a_w: &Vec3, a_cos: f32, b_w: &Vec3, b_cos: f32) -> (Vec3, f32) {
+    // Smallest cone of directions holding both cones
+    let theta_a = a_cos.clamp(-1.0, 1.0).acos();
+    let theta_b = b_cos.clamp(-1.0, 1.0).acos();
+    let theta_d = a_w.dot(b_w).clamp(-1.0, 1.0).acos();
+
+    if (theta_d + theta_b).min(PI) <= theta_a {
+        return (*a_w, a_cos);
+    }
+    if (theta_d + theta_a).min(PI) <= theta_b {
+        return (*b_w, b_cos);
+    }
+
+    let theta_o = (theta_a + theta_d + theta_b) / 2.0;
+    let axis = a_w.cross(b_w);
+    if theta_o >= PI || axis.length_squared() <= 0.0 {
+        return (*a_w, -1.0);
+    }
+
+    // Rotate the first axis towards the second to the middle of the combined cone
+    let theta_r = theta_o - theta_a;
+    let k = axis.unit_vector();
+    let w = theta_r.cos() * *a_w
+        + theta_r.sin() * k.cross(a_w)
+        + ((1.0 - theta_r.cos()) * k.dot(a_w)) * k;
+
+    (w.unit_vector(), theta_o.cos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{color::Color, light::point::Falloff};
+
+    fn point_bounds(p: Point3, phi: f32) -> LightBounds {
+        LightBounds::new(
+            Aabb::from_points(&p, &p),
+            phi,
+            Vec3::new(0.0, 0.0, 1.0),
+            -1.0,
+            0.0,
+            false,
+        )
+    }
+
+    #[test]
+    fn closer_lights_are_more_important() {
+        let p = Point3::new(0.0, 0.0, 0.0);
+        let n = Vec3::default();
+
+        let near = point_bounds(Point3::new(1.0, 0.0, 0.0), 1.0);
+        let far = point_bounds(Point3::new(10.0, 0.0, 0.0), 1.0);
+        assert!(near.importance(&p, &n) > far.importance(&p, &n));
+    }
+
+    #[test]
+    fn lights_behind_a_one_sided_surface_are_ignored() {
+        let bounds = LightBounds::new(
+            Aabb::from_points(&Point3::new(-1.0, -1.0, 0.0), &Point3::new(1.0, 1.0, 0.0)),
+            1.0,
+            Vec3::new(0.0, 0.0, 1.0),
+            1.0,
+            0.0,
+            false,
+        );
+        let n = Vec3::default();
+
+        assert!(bounds.importance(&Point3::new(0.0, 0.0, 5.0), &n) > 0.0);
+        assert_eq!(bounds.importance(&Point3::new(0.0, 0.0, -5.0), &n), 0.0);
+    }
+
+    #[test]
+    fn nodes_sum_the_power_of_their_lights() {
+        let lights = [
+            LightType::point(
+                Point3::new(-1.0, 3.0, 0.0),
+                Color::new(1.0, 1.0, 1.0),
+                Falloff::InverseSquare,
+            ),
+            LightType::point(
+                Point3::new(1.0, 3.0, 0.0),
+                Color::new(2.0, 2.0, 2.0),
+                Falloff::InverseSquare,
+            ),
+        ];
+        let bvh = LightBvh::new(&lights);
+
+        let phi: f32 = lights.iter().map(|light| light.bounds().unwrap().phi).sum();
+        assert!((bvh.nodes[0].bounds.phi - phi).abs() < 1e-5 * phi);
+    }
+
+    #[test]
+    fn pmf_matches_sampling() {
+        let p = Point3::new(0.0, 1.0, 0.0);
+        let n = Vec3::new(0.0, -1.0, 0.0);
+        let lights: Vec<_> = (0..5)
+            .map(|i| {
+                LightType::point(
+                    Point3::new(i as f32, 3.0, 0.0),
+                    Color::new(1.0, 1.0, 1.0),
+                    Falloff::InverseSquare,
+                )
+            })
+            .collect();
+        let bvh = LightBvh::new(&lights);
+
+        let total: f32 = (0..lights.len()).map(|i| bvh.pmf(&p, &n, i)).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+
+        let (light, pmf) = bvh.sample(&p, &n).unwrap();
+        assert!((bvh.pmf(&p, &n, light) - pmf).abs() < 1e-6);
+    }
+}
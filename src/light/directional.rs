@@ -0,0 +1,89 @@
+use std::f32::consts::PI;
+
+use crate::{
+    color::Color,
+    onb::Onb,
+    utility::{degree_to_radians, random_float},
+    vec3::{Point3, Vec3},
+};
+
+use super::{Light, LightSample};
+
+#[derive(Clone)]
+pub struct DirectionalLight {
+    // Unit direction pointing from the scene towards the light
+    to_light: Vec3,
+    // Irradiance arriving on a surface facing the light
+    irradiance: Color,
+    // Cosine of the angular radius of the light's disk as seen from the scene
+    cos_radius: f32,
+}
+
+impl DirectionalLight {
+    pub fn new(direction: Vec3, irradiance: Color, angular_radius: f32) -> Self {
+        // `direction` is the direction the light travels in, `angular_radius` is in degrees and
+        // softens shadows the way the finite disk of the sun does
+        Self {
+            to_light: -direction.unit_vector(),
+            irradiance,
+            cos_radius: degree_to_radians(angular_radius.clamp(0.0, 90.0)).cos(),
+        }
+    }
+
+    fn sample_direction(&self) -> Vec3 {
+        if self.cos_radius >= 1.0 {
+            return self.to_light;
+        }
+
+        // Uniformly pick a direction in the cone subtended by the light's disk
+        let r1 = random_float();
+        let r2 = random_float();
+        let z = 1.0 + r2 * (self.cos_radius - 1.0);
+        let phi = 2.0 * PI * r1;
+        let sin_theta = (1.0 - z * z).sqrt();
+
+        Onb::new(&self.to_light).transform(&Vec3::new(
+            phi.cos() * sin_theta,
+            phi.sin() * sin_theta,
+            z,
+        ))
+    }
+}
+
+impl Light for DirectionalLight {
+    fn sample(&self, _p: &Point3) -> Option<LightSample> {
+        Some(LightSample {
+            direction: self.sample_direction(),
+            distance: f32::INFINITY,
+            radiance: self.irradiance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_uniformly_over_the_disk_of_the_light() {
+        let light =
+            DirectionalLight::new(Vec3::new(0.0, -1.0, 0.0), Color::new(1.0, 1.0, 1.0), 10.0);
+        let cos_radius = degree_to_radians(10.0).cos();
+
+        // Uniform over the cone, the cosine to its axis is uniform between cos_radius and one
+        let n = 20000;
+        let mut mean_cos = 0.0;
+        for _ in 0..n {
+            let sample = light.sample(&Point3::default()).unwrap();
+            assert_eq!(sample.distance, f32::INFINITY);
+            assert!(sample.direction.y() >= cos_radius - 1e-5);
+            mean_cos += sample.direction.y() / n as f32;
+        }
+        assert!((mean_cos - (1.0 + cos_radius) / 2.0).abs() < 1e-3);
+
+        // Without a disk the light comes from exactly one direction
+        let sharp = DirectionalLight::new(Vec3::new(1.0, 0.0, 0.0), Color::new(1.0, 1.0, 1.0), 0.0);
+        let sample = sharp.sample(&Point3::default()).unwrap();
+        assert!((sample.direction.x() + 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,268 @@
+use std::io::{Error, ErrorKind};
+
+use crate::{onb::Onb, utility::read_asset, vec3::Vec3};
+
+#[derive(Debug, Clone)]
+pub struct IesProfile {
+    // Angles from the nadir of the luminaire in degrees, ascending
+    vertical_angles: Vec<f32>,
+    // Angles around the nadir axis in degrees, ascending
+    horizontal_angles: Vec<f32>,
+    // Luminous intensity in candela for every horizontal angle, then every vertical angle
+    candela: Vec<Vec<f32>>,
+    peak_candela: f32,
+}
+
+impl IesProfile {
+    pub fn load(filename: &str) -> Result<IesProfile, Error> {
+        Self::parse(&read_asset(filename)?)
+    }
+
+    pub fn parse(text: &str) -> Result<IesProfile, Error> {
+        // Read an IESNA LM-63 photometric file with type C photometry, the convention used by
+        // nearly all architectural luminaires
+        let mut lines = text.lines();
+
+        // Skip the free form header and keywords up to the tilt specification
+        let tilt = lines
+            .by_ref()
+            .map(str::trim)
+            .find(|line| line.starts_with("TILT="))
+            .ok_or_else(|| invalid("missing TILT line"))?;
+
+        let mut values = lines
+            .flat_map(str::split_whitespace)
+            .map(|value| value.parse::<f32>());
+        let mut next = || -> Result<f32, Error> {
+            values
+                .next()
+                .ok_or_else(|| invalid("unexpected end of file"))?
+                .map_err(|e| invalid(&e.to_string()))
+        };
+
+        // Lamp tilt data only matters for lamps that change output with orientation, skip it
+        if tilt != "TILT=NONE" && tilt != "TILT=INCLUDE" {
+            return Err(invalid(
+                "TILT files are not supported, include the data instead",
+            ));
+        }
+        if tilt == "TILT=INCLUDE" {
+            let _geometry = next()?;
+            let pairs = next()? as usize;
+            for _ in 0..2 * pairs {
+                next()?;
+            }
+        }
+
+        let _lamps = next()?;
+        let _lumens_per_lamp = next()?;
+        let multiplier = next()?;
+        let vertical_count = next()? as usize;
+        let horizontal_count = next()? as usize;
+        let photometric_type = next()? as u32;
+        let _units = next()?;
+        let _size = [next()?, next()?, next()?];
+        let ballast_factor = next()?;
+        let _ballast_lamp_factor = next()?;
+        let _input_watts = next()?;
+
+        if photometric_type != 1 {
+            return Err(invalid("only type C photometry is supported"));
+        }
+        if vertical_count == 0 || horizontal_count == 0 {
+            return Err(invalid("no photometric angles"));
+        }
+
+        let vertical_angles = (0..vertical_count)
+            .map(|_| next())
+            .collect::<Result<Vec<_>, _>>()?;
+        let horizontal_angles = (0..horizontal_count)
+            .map(|_| next())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let scale = multiplier * ballast_factor;
+        let candela = (0..horizontal_count)
+            .map(|_| {
+                (0..vertical_count)
+                    .map(|_| next().map(|value| scale * value))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let peak_candela = candela.iter().flatten().fold(0.0_f32, |a, b| a.max(*b));
+
+        Ok(IesProfile {
+            vertical_angles,
+            horizontal_angles,
+            candela,
+            peak_candela,
+        })
+    }
+
+    pub fn peak_candela(&self) -> f32 {
+        self.peak_candela
+    }
+
+    pub fn candela(&self, vertical: f32, horizontal: f32) -> f32 {
+        // Interpolated luminous intensity towards the given angles in degrees
+        let horizontal = self.fold_horizontal(horizontal);
+
+        let Some((v0, v1, tv)) = bracket(&self.vertical_angles, vertical) else {
+            return 0.0;
+        };
+        let (h0, h1, th) = bracket(&self.horizontal_angles, horizontal).unwrap_or((0, 0, 0.0));
+
+        let lerp = |row: &Vec<f32>| (1.0 - tv) * row[v0] + tv * row[v1];
+        (1.0 - th) * lerp(&self.candela[h0]) + th * lerp(&self.candela[h1])
+    }
+
+    pub fn relative_intensity(&self, frame: &Onb, direction: &Vec3) -> f32 {
+        // Intensity towards a unit direction leaving the luminaire relative to the peak, where the
+        // frame's w axis is the nadir and its u axis the zero horizontal angle
+        if self.peak_candela <= 0.0 {
+            return 0.0;
+        }
+
+        let vertical = direction
+            .dot(frame.w())
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees();
+        let horizontal = direction
+            .dot(frame.v())
+            .atan2(direction.dot(frame.u()))
+            .to_degrees()
+            .rem_euclid(360.0);
+
+        self.candela(vertical, horizontal) / self.peak_candela
+    }
+
+    fn fold_horizontal(&self, horizontal: f32) -> f32 {
+        // Measurements only cover the part of the circle that isn't implied by symmetry
+        let last = *self.horizontal_angles.last().unwrap_or(&0.0);
+        let horizontal = horizontal.rem_euclid(360.0);
+
+        if last <= 0.0 {
+            0.0
+        } else if last <= 90.0 {
+            let half = if horizontal > 180.0 {
+                360.0 - horizontal
+            } else {
+                horizontal
+            };
+            if half > 90.0 {
+                180.0 - half
+            } else {
+                half
+            }
+        } else if last <= 180.0 && horizontal > 180.0 {
+            360.0 - horizontal
+        } else {
+            horizontal
+        }
+    }
+}
+
+fn bracket(angles: &[f32], angle: f32) -> Option<(usize, usize, f32)> {
+    // Indices of the measured angles either side of `angle` and how far it lies between them,
+    // or None when it lies outside the measured range
+    let first = *angles.first()?;
+    let last = *angles.last()?;
+    if angle < first || angle > last {
+        return None;
+    }
+    if angles.len() == 1 {
+        return Some((0, 0, 0.0));
+    }
+
+    let upper = angles
+        .partition_point(|a| *a <= angle)
+        .clamp(1, angles.len() - 1);
+    let lower = upper - 1;
+    let span = angles[upper] - angles[lower];
+    let t = if span > 0.0 {
+        (angle - angles[lower]) / span
+    } else {
+        0.0
+    };
+
+    Some((lower, upper, t.clamp(0.0, 1.0)))
+}
+
+fn invalid(message: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("invalid IES file: {message}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUADRANT: &str = "IESNA:LM-63-2002\n\
+        [TEST] QUADRANT\n\
+        TILT=NONE\n\
+        1 1000 2.0 3 2 1 2 0 0 0\n\
+        1.0 1.0 10\n\
+        0 45 90\n\
+        0 90\n\
+        100 50 0\n\
+        200 100 0\n";
+
+    #[test]
+    fn parses_header_and_values() {
+        let profile = IesProfile::parse(QUADRANT).unwrap();
+
+        assert_eq!(profile.peak_candela(), 400.0);
+        assert_eq!(profile.candela(0.0, 0.0), 200.0);
+        assert_eq!(profile.candela(22.5, 0.0), 150.0);
+        assert_eq!(profile.candela(0.0, 45.0), 300.0);
+        assert_eq!(profile.candela(120.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn unfolds_quadrant_symmetry() {
+        let profile = IesProfile::parse(QUADRANT).unwrap();
+
+        assert_eq!(profile.candela(45.0, 180.0), profile.candela(45.0, 0.0));
+        assert_eq!(profile.candela(45.0, 270.0), profile.candela(45.0, 90.0));
+        assert_eq!(profile.candela(45.0, 300.0), profile.candela(45.0, 60.0));
+    }
+
+    #[test]
+    fn lines_up_the_zero_plane_with_the_reference() {
+        // Lit only towards the zero horizontal angle, measured all the way round
+        let profile = IesProfile::parse(
+            "IESNA:LM-63-2002\n\
+            TILT=NONE\n\
+            1 1000 1.0 2 5 1 2 0 0 0\n\
+            1.0 1.0 10\n\
+            0 90\n\
+            0 90 180 270 360\n\
+            100 100\n\
+            0 0\n\
+            0 0\n\
+            0 0\n\
+            100 100\n",
+        )
+        .unwrap();
+        let down = Vec3::new(0.0, -1.0, 0.0);
+        let tilted = |x: f32, z: f32| Vec3::new(x, -1.0, z).unit_vector();
+
+        for reference in [Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.5, -1.0)] {
+            let frame = Onb::with_reference(&down, &reference);
+            let across = reference - reference.dot(&down) * down;
+            let (x, z) = (across.x() / across.length(), across.z() / across.length());
+
+            assert!(profile.relative_intensity(&frame, &tilted(x, z)) > 0.99);
+            assert!(profile.relative_intensity(&frame, &tilted(-x, -z)) < 0.01);
+            assert!(profile.relative_intensity(&frame, &tilted(-z, x)) < 0.01);
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_files() {
+        assert!(IesProfile::parse("IESNA:LM-63-2002\nTILT=NONE\n1 1000 1.0 3").is_err());
+    }
+}
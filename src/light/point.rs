@@ -0,0 +1,123 @@
+use std::{f32::consts::PI, sync::Arc};
+
+use crate::{
+    aabb::Aabb,
+    color::Color,
+    onb::Onb,
+    vec3::{Point3, Vec3},
+};
+
+use super::{bvh::LightBounds, ies::IesProfile, Light, LightSample};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Falloff {
+    // Same brightness at any distance
+    None,
+    // Physically based falloff with the square of the distance
+    InverseSquare,
+}
+
+impl Falloff {
+    pub fn attenuation(&self, distance: f32) -> f32 {
+        match self {
+            Falloff::None => 1.0,
+            Falloff::InverseSquare => 1.0 / (distance * distance),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PointLight {
+    position: Point3,
+    // Radiant intensity, emitted equally in all directions
+    intensity: Color,
+    falloff: Falloff,
+    // Photometric distribution shaping the intensity, oriented by the frame
+    profile: Option<(Arc<IesProfile>, Onb)>,
+}
+
+impl PointLight {
+    pub fn new(position: Point3, intensity: Color, falloff: Falloff) -> Self {
+        Self {
+            position,
+            intensity,
+            falloff,
+            profile: None,
+        }
+    }
+
+    pub fn with_profile(self, profile: IesProfile, nadir: Vec3, c0: Vec3) -> Self {
+        // Shape the light with a measured luminaire pointing down `nadir`, with its zero
+        // horizontal angle turned towards `c0`. `intensity` is then the intensity in the
+        // profile's brightest direction.
+        Self {
+            profile: Some((Arc::new(profile), Onb::with_reference(&nadir, &c0))),
+            ..self
+        }
+    }
+}
+
+impl Light for PointLight {
+    fn sample(&self, p: &Point3) -> Option<LightSample> {
+        let to_light = self.position - p;
+        let distance = to_light.length();
+        if distance <= 0.0 {
+            return None;
+        }
+
+        let direction = to_light / distance;
+        let distribution = self.profile.as_ref().map_or(1.0, |(profile, frame)| {
+            profile.relative_intensity(frame, &-direction)
+        });
+        if distribution <= 0.0 {
+            return None;
+        }
+
+        Some(LightSample {
+            direction,
+            distance,
+            radiance: (distribution * self.falloff.attenuation(distance)) * self.intensity,
+        })
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        // Emits in every direction, a profile only ever dims the intensity
+        Some(LightBounds::new(
+            Aabb::from_points(&self.position, &self.position),
+            4.0 * PI * self.intensity.luminance(),
+            Vec3::new(0.0, 0.0, 1.0),
+            -1.0,
+            0.0,
+            false,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_off_with_the_square_of_the_distance() {
+        let light = PointLight::new(
+            Point3::new(0.0, 4.0, 0.0),
+            Color::new(8.0, 8.0, 8.0),
+            Falloff::InverseSquare,
+        );
+        let sample = light.sample(&Point3::new(0.0, 2.0, 0.0)).unwrap();
+        assert!((sample.distance - 2.0).abs() < 1e-6);
+        assert!((sample.direction.y() - 1.0).abs() < 1e-6);
+        assert!((sample.radiance.x() - 2.0).abs() < 1e-6);
+
+        // Twice as far is a quarter as bright
+        let far = light.sample(&Point3::new(0.0, 0.0, 0.0)).unwrap();
+        assert!((far.radiance.x() - 0.5).abs() < 1e-6);
+
+        let flat = PointLight::new(Point3::default(), Color::new(3.0, 3.0, 3.0), Falloff::None);
+        let sample = flat.sample(&Point3::new(10.0, 0.0, 0.0)).unwrap();
+        assert!((sample.radiance.x() - 3.0).abs() < 1e-6);
+
+        // Nothing can be sent from the light to itself
+        assert!(flat.sample(&Point3::default()).is_none());
+    }
+}
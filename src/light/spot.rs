@@ -0,0 +1,133 @@
+use std::{f32::consts::PI, sync::Arc};
+
+use crate::{
+    aabb::Aabb,
+    color::Color,
+    onb::Onb,
+    utility::{degree_to_radians, smoothstep},
+    vec3::{Point3, Vec3},
+};
+
+use super::{bvh::LightBounds, ies::IesProfile, Light, LightSample};
+
+#[derive(Clone)]
+pub struct SpotLight {
+    position: Point3,
+    // Unit axis of the cone of light
+    direction: Vec3,
+    // Radiant intensity along the axis of the cone
+    intensity: Color,
+    // Cosines of the angles where the edge of the cone starts and finishes fading out
+    cos_inner: f32,
+    cos_outer: f32,
+    // Photometric distribution shaping the intensity, its nadir along the axis of the cone
+    profile: Option<(Arc<IesProfile>, Onb)>,
+}
+
+impl SpotLight {
+    pub fn new(
+        position: Point3,
+        direction: Vec3,
+        intensity: Color,
+        inner_angle: f32,
+        outer_angle: f32,
+    ) -> Self {
+        // Angles are the half angles of the cone in degrees
+        let outer_angle = outer_angle.max(inner_angle);
+        Self {
+            position,
+            direction: direction.unit_vector(),
+            intensity,
+            cos_inner: degree_to_radians(inner_angle).cos(),
+            cos_outer: degree_to_radians(outer_angle).cos(),
+            profile: None,
+        }
+    }
+
+    pub fn with_profile(self, profile: IesProfile, c0: Vec3) -> Self {
+        // The profile's nadir runs down the axis, with its zero horizontal angle towards `c0`
+        Self {
+            profile: Some((Arc::new(profile), Onb::with_reference(&self.direction, &c0))),
+            ..self
+        }
+    }
+}
+
+impl Light for SpotLight {
+    fn sample(&self, p: &Point3) -> Option<LightSample> {
+        let to_light = self.position - p;
+        let distance = to_light.length();
+        if distance <= 0.0 {
+            return None;
+        }
+
+        let direction = to_light / distance;
+
+        // Smoothly fade the light out between the inner and outer edges of the cone
+        let cos_theta = (-direction).dot(&self.direction);
+        let edge = if self.cos_inner > self.cos_outer {
+            smoothstep(self.cos_outer, self.cos_inner, cos_theta)
+        } else if cos_theta >= self.cos_outer {
+            1.0
+        } else {
+            0.0
+        };
+        let distribution = self.profile.as_ref().map_or(1.0, |(profile, frame)| {
+            profile.relative_intensity(frame, &-direction)
+        });
+        if edge * distribution <= 0.0 {
+            return None;
+        }
+
+        Some(LightSample {
+            direction,
+            distance,
+            radiance: (edge * distribution / (distance * distance)) * self.intensity,
+        })
+    }
+
+    fn bounds(&self) -> Option<LightBounds> {
+        // Full intensity inside the inner cone, fading out over the angle to the outer one
+        let theta_e = self.cos_outer.acos() - self.cos_inner.acos();
+        Some(LightBounds::new(
+            Aabb::from_points(&self.position, &self.position),
+            4.0 * PI * self.intensity.luminance(),
+            self.direction,
+            self.cos_inner,
+            theta_e.cos(),
+            false,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fades_out_between_the_inner_and_outer_cones() {
+        // Pointing straight down from one unit up, full strength out to 30 degrees and dark
+        // beyond 45
+        let light = SpotLight::new(
+            Point3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, -1.0, 0.0),
+            Color::new(1.0, 1.0, 1.0),
+            30.0,
+            45.0,
+        );
+        let at_angle = |degrees: f32| {
+            let offset = degree_to_radians(degrees).tan();
+            light
+                .sample(&Point3::new(offset, 0.0, 0.0))
+                .map_or(0.0, |sample| {
+                    sample.radiance.x() * sample.distance * sample.distance
+                })
+        };
+
+        assert!((at_angle(0.0) - 1.0).abs() < 1e-5);
+        assert!((at_angle(25.0) - 1.0).abs() < 1e-5);
+        let edge = at_angle(38.0);
+        assert!(edge > 0.0 && edge < 1.0);
+        assert_eq!(at_angle(50.0), 0.0);
+    }
+}
@@ -1,10 +1,17 @@
 #![feature(portable_simd)]
 mod aabb;
+mod animation;
+mod background;
 mod camera;
 mod color;
+mod distribution;
 mod hittable;
+mod interior;
 mod interval;
+mod light;
+mod mat4;
 mod material;
+mod onb;
 mod perlin;
 mod ray;
 mod scenes;
@@ -18,8 +25,16 @@ use simd_vec3 as vec3;
 #[cfg(not(feature = "simd"))]
 pub mod vec3;
 
+use camera::{
+    aperture::Aperture,
+    lens::LensSystem,
+    projection::{FisheyeMapping, Projection},
+    shutter::{Shutter, ShutterCurve},
+    stereo::{Convergence, Stereo, StereoLayout},
+};
+
 fn main() {
-    match 10 {
+    match 0 {
         1 => scenes::bouncing_spheres(),
         2 => scenes::checkered_spheres(),
         3 => scenes::earth(),
@@ -29,6 +44,86 @@ fn main() {
         7 => scenes::cornell_box(),
         8 => scenes::cornell_smoke(),
         9 => scenes::final_scene(800, 10000, 40),
+        10 => scenes::glass_of_water(),
+        11 => scenes::delta_lights(),
+        12 => scenes::environment_map(),
+        13 => scenes::outdoor_sky(),
+        14 => scenes::textured_background(),
+        15 => scenes::area_lights(),
+        16 => scenes::ies_lights(),
+        17 => scenes::many_lights(),
+        18 => scenes::orthographic(),
+        19 => scenes::panorama(Projection::Equirectangular, None),
+        20 => scenes::panorama(Projection::CubeMap, None),
+        21 => scenes::panorama(
+            Projection::Fisheye {
+                mapping: FisheyeMapping::Equidistant,
+                fov: 180.0,
+            },
+            None,
+        ),
+        22 => scenes::panorama(
+            Projection::Fisheye {
+                mapping: FisheyeMapping::Equisolid,
+                fov: 360.0,
+            },
+            None,
+        ),
+        23 => scenes::panorama(
+            Projection::Fisheye {
+                mapping: FisheyeMapping::Stereographic,
+                fov: 220.0,
+            },
+            None,
+        ),
+        24 => scenes::panorama(
+            Projection::Perspective,
+            Some(Stereo::new(
+                0.064,
+                Convergence::OffAxis,
+                StereoLayout::SideBySide,
+            )),
+        ),
+        25 => scenes::panorama(
+            Projection::Perspective,
+            Some(Stereo::new(
+                0.064,
+                Convergence::ToeIn,
+                StereoLayout::SideBySide,
+            )),
+        ),
+        26 => scenes::panorama(
+            Projection::Equirectangular,
+            Some(Stereo::new(
+                0.064,
+                Convergence::Parallel,
+                StereoLayout::OverUnder,
+            )),
+        ),
+        27 => scenes::bokeh(Aperture::polygon(6, 15.0)),
+        28 => scenes::bokeh(Aperture::mask(
+            utility::load_image("aperture_star.png").expect("aperture image"),
+        )),
+        29 => scenes::realistic_lens(
+            LensSystem::load("dgauss.50mm.dat").expect("Failed to load the lens prescription"),
+        ),
+        30 => scenes::motion_blur(Shutter::new(0.0, 1.0, ShutterCurve::trapezoid(0.25))),
+        // A strobe firing as the shutter opens, halfway through and as it closes
+        31 => scenes::motion_blur(Shutter::new(
+            0.0,
+            1.0,
+            ShutterCurve::tabulated((0..=20).map(|i| (i % 10 == 0) as u32 as f32).collect()),
+        )),
+        32 => scenes::motion_blur(Shutter::new(0.0, 0.05, ShutterCurve::Box).rolling(0.95)),
+        33 => scenes::animated_boxes(),
+        34 => scenes::turntable(),
+        35 => scenes::transforms(),
+        36 => scenes::forest(),
+        37 => scenes::primitives(),
+        38 => scenes::planar_shapes(),
+        39 => scenes::csg(),
+        40 => scenes::distance_fields(),
+        41 => scenes::terrain(),
         _ => scenes::final_scene(400, 250, 4),
     }
 }
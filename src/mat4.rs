@@ -0,0 +1,215 @@
+use std::ops::Mul;
+
+use crate::{
+    utility::degree_to_radians,
+    vec3::{Point3, Vec3},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Mat4 {
+    // Rows of the matrix, applied to column vectors with points having a w of one
+    pub m: [[f32; 4]; 4],
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mat4 {
+    pub const IDENTITY: Mat4 = Mat4 {
+        m: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    };
+
+    pub fn translation(offset: Vec3) -> Mat4 {
+        let mut tmp = Self::IDENTITY;
+        for i in 0..3 {
+            tmp.m[i][3] = offset[i];
+        }
+
+        tmp
+    }
+
+    pub fn scaling(factors: Vec3) -> Mat4 {
+        let mut tmp = Self::IDENTITY;
+        for i in 0..3 {
+            tmp.m[i][i] = factors[i];
+        }
+
+        tmp
+    }
+
+    pub fn rotation(axis: Vec3, angle: f32) -> Mat4 {
+        // Rotation by `angle` degrees counterclockwise about `axis`, looking down the axis
+        // towards the origin
+        let a = axis.unit_vector();
+        let (sin_theta, cos_theta) = degree_to_radians(angle).sin_cos();
+        let k = 1.0 - cos_theta;
+
+        Mat4 {
+            m: [
+                [
+                    a.x() * a.x() * k + cos_theta,
+                    a.x() * a.y() * k - a.z() * sin_theta,
+                    a.x() * a.z() * k + a.y() * sin_theta,
+                    0.0,
+                ],
+                [
+                    a.y() * a.x() * k + a.z() * sin_theta,
+                    a.y() * a.y() * k + cos_theta,
+                    a.y() * a.z() * k - a.x() * sin_theta,
+                    0.0,
+                ],
+                [
+                    a.z() * a.x() * k - a.y() * sin_theta,
+                    a.z() * a.y() * k + a.x() * sin_theta,
+                    a.z() * a.z() * k + cos_theta,
+                    0.0,
+                ],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    pub fn inverse(&self) -> Option<Mat4> {
+        // Gauss-Jordan elimination with partial pivoting, None for a singular matrix
+        let mut a = self.m;
+        let mut inv = Self::IDENTITY.m;
+
+        for col in 0..4 {
+            let pivot = (col..4)
+                .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+                .unwrap_or(col);
+            if a[pivot][col].abs() < 1e-12 {
+                return None;
+            }
+            a.swap(col, pivot);
+            inv.swap(col, pivot);
+
+            let scale = 1.0 / a[col][col];
+            for j in 0..4 {
+                a[col][j] *= scale;
+                inv[col][j] *= scale;
+            }
+
+            for row in 0..4 {
+                if row == col {
+                    continue;
+                }
+                let factor = a[row][col];
+                for j in 0..4 {
+                    a[row][j] -= factor * a[col][j];
+                    inv[row][j] -= factor * inv[col][j];
+                }
+            }
+        }
+
+        Some(Mat4 { m: inv })
+    }
+
+    pub fn transform_point(&self, p: &Point3) -> Point3 {
+        let m = &self.m;
+        let x = m[0][0] * p.x() + m[0][1] * p.y() + m[0][2] * p.z() + m[0][3];
+        let y = m[1][0] * p.x() + m[1][1] * p.y() + m[1][2] * p.z() + m[1][3];
+        let z = m[2][0] * p.x() + m[2][1] * p.y() + m[2][2] * p.z() + m[2][3];
+        let w = m[3][0] * p.x() + m[3][1] * p.y() + m[3][2] * p.z() + m[3][3];
+
+        if w == 1.0 {
+            Point3::new(x, y, z)
+        } else {
+            Point3::new(x, y, z) / w
+        }
+    }
+
+    pub fn transform_vector(&self, v: &Vec3) -> Vec3 {
+        let m = &self.m;
+        Vec3::new(
+            m[0][0] * v.x() + m[0][1] * v.y() + m[0][2] * v.z(),
+            m[1][0] * v.x() + m[1][1] * v.y() + m[1][2] * v.z(),
+            m[2][0] * v.x() + m[2][1] * v.y() + m[2][2] * v.z(),
+        )
+    }
+
+    pub fn transform_normal(&self, n: &Vec3) -> Vec3 {
+        // Multiplies by the transpose, so called on the inverse of the matrix that moves the
+        // surface it keeps the normal perpendicular to that surface. Not normalized.
+        let m = &self.m;
+        Vec3::new(
+            m[0][0] * n.x() + m[1][0] * n.y() + m[2][0] * n.z(),
+            m[0][1] * n.x() + m[1][1] * n.y() + m[2][1] * n.z(),
+            m[0][2] * n.x() + m[1][2] * n.y() + m[2][2] * n.z(),
+        )
+    }
+}
+
+impl Mul<Mat4> for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        // The transform applying `rhs` first and then `self`
+        let mut tmp = Mat4 { m: [[0.0; 4]; 4] };
+        for i in 0..4 {
+            for j in 0..4 {
+                tmp.m[i][j] = (0..4).map(|k| self.m[i][k] * rhs.m[k][j]).sum();
+            }
+        }
+
+        tmp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(a: &Vec3, b: &Vec3) {
+        assert!((*a - *b).length() < 1e-5, "{a} != {b}");
+    }
+
+    #[test]
+    fn rotates_about_any_axis() {
+        let about_y = Mat4::rotation(Vec3::new(0.0, 1.0, 0.0), 90.0);
+        assert_near(
+            &about_y.transform_vector(&Vec3::new(1.0, 0.0, 0.0)),
+            &Vec3::new(0.0, 0.0, -1.0),
+        );
+
+        // A third of a turn about the diagonal cycles the axes
+        let about_diagonal = Mat4::rotation(Vec3::new(1.0, 1.0, 1.0), 120.0);
+        assert_near(
+            &about_diagonal.transform_vector(&Vec3::new(1.0, 0.0, 0.0)),
+            &Vec3::new(0.0, 1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn inverse_undoes_composition() {
+        let m = Mat4::translation(Vec3::new(1.0, -2.0, 3.0))
+            * Mat4::rotation(Vec3::new(1.0, 2.0, 0.5), 33.0)
+            * Mat4::scaling(Vec3::new(2.0, 0.5, 1.5));
+        let inverse = m.inverse().unwrap();
+
+        let p = Point3::new(0.3, -0.7, 2.0);
+        assert_near(&inverse.transform_point(&m.transform_point(&p)), &p);
+        assert!(Mat4::scaling(Vec3::new(1.0, 0.0, 1.0)).inverse().is_none());
+    }
+
+    #[test]
+    fn normals_stay_perpendicular() {
+        // A surface tilted at 45 degrees, squashed to half its height
+        let m = Mat4::scaling(Vec3::new(1.0, 0.5, 1.0));
+        let tangent = m.transform_vector(&Vec3::new(1.0, 1.0, 0.0));
+        let normal = m
+            .inverse()
+            .unwrap()
+            .transform_normal(&Vec3::new(1.0, -1.0, 0.0));
+
+        assert!(tangent.dot(&normal).abs() < 1e-6);
+    }
+}
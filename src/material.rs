@@ -21,6 +21,12 @@ pub enum MaterialType {
     Isotropic(Isotropic),
 }
 
+impl From<DiffuseLight> for MaterialType {
+    fn from(value: DiffuseLight) -> Self {
+        MaterialType::DiffuseLight(value)
+    }
+}
+
 impl MaterialType {
     pub fn metal(albedo: Color, fuzz: f32) -> MaterialType {
         MaterialType::Metal(Metal::new(albedo, fuzz))
@@ -34,10 +40,22 @@ impl MaterialType {
         MaterialType::Dialectric(Dialectric::new(refractive_index))
     }
 
+    pub fn nested_dialectric(
+        refractive_index: f32,
+        priority: i32,
+        absorption: Color,
+    ) -> MaterialType {
+        MaterialType::Dialectric(Dialectric::nested(refractive_index, priority, absorption))
+    }
+
     pub fn diffuse_light(tex: TextureType) -> MaterialType {
         MaterialType::DiffuseLight(DiffuseLight::new(tex))
     }
 
+    pub fn one_sided_light(tex: TextureType) -> MaterialType {
+        MaterialType::DiffuseLight(DiffuseLight::one_sided(tex))
+    }
+
     pub fn isotropic(tex: TextureType) -> MaterialType {
         MaterialType::Isotropic(Isotropic::new(tex))
     }
@@ -59,13 +77,24 @@ impl MaterialType {
         }
     }
 
-    pub fn emitted(&self, u: f32, v: f32, p: &Point3) -> Color {
+    pub fn scattering_pdf(&self, ray_in: &Ray, record: &HitRecord, scattered: &Ray) -> f32 {
         match self {
-            MaterialType::Lambertian(mat) => mat.emitted(u, v, p),
-            MaterialType::Metal(mat) => mat.emitted(u, v, p),
-            MaterialType::Dialectric(mat) => mat.emitted(u, v, p),
-            MaterialType::DiffuseLight(mat) => mat.emitted(u, v, p),
-            MaterialType::Isotropic(mat) => mat.emitted(u, v, p),
+            MaterialType::Lambertian(mat) => mat.scattering_pdf(ray_in, record, scattered),
+            MaterialType::Metal(mat) => mat.scattering_pdf(ray_in, record, scattered),
+            MaterialType::Dialectric(mat) => mat.scattering_pdf(ray_in, record, scattered),
+            MaterialType::DiffuseLight(mat) => mat.scattering_pdf(ray_in, record, scattered),
+            MaterialType::Isotropic(mat) => mat.scattering_pdf(ray_in, record, scattered),
+            MaterialType::None => 0.0,
+        }
+    }
+
+    pub fn emitted(&self, ray_in: &Ray, record: &HitRecord, u: f32, v: f32, p: &Point3) -> Color {
+        match self {
+            MaterialType::Lambertian(mat) => mat.emitted(ray_in, record, u, v, p),
+            MaterialType::Metal(mat) => mat.emitted(ray_in, record, u, v, p),
+            MaterialType::Dialectric(mat) => mat.emitted(ray_in, record, u, v, p),
+            MaterialType::DiffuseLight(mat) => mat.emitted(ray_in, record, u, v, p),
+            MaterialType::Isotropic(mat) => mat.emitted(ray_in, record, u, v, p),
             MaterialType::None => Color::default(),
         }
     }
@@ -82,7 +111,13 @@ pub trait Material {
         false
     }
 
-    fn emitted(&self, _u: f32, _v: f32, _p: &Point3) -> Color {
+    fn scattering_pdf(&self, _ray_in: &Ray, _record: &HitRecord, _scattered: &Ray) -> f32 {
+        // Density of scattering into a direction, which is also the density `scatter` samples
+        // directions with. Mirror-like materials that can't be lit by a single direction return 0.
+        0.0
+    }
+
+    fn emitted(&self, _ray_in: &Ray, _record: &HitRecord, _u: f32, _v: f32, _p: &Point3) -> Color {
         Color::default()
     }
 }
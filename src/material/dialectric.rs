@@ -1,4 +1,6 @@
-use crate::{color::Color, hittable::HitRecord, ray::Ray, utility::random_float};
+use crate::{
+    color::Color, hittable::HitRecord, interior::Interior, ray::Ray, utility::random_float,
+};
 
 use super::Material;
 
@@ -7,35 +9,48 @@ pub struct Dialectric {
     // Refractive index in vacuum or air, or the ratio of the material's refractive index over
     // the refractive index of the enclosing media
     refractive_index: f32,
+    // Nesting priority, the highest priority dielectric owns the volume where objects overlap
+    priority: i32,
+    // Beer-Lambert absorption coefficient of the medium inside the object
+    absorption: Color,
 }
 
 impl Dialectric {
     pub fn new(refractive_index: f32) -> Self {
-        Self { refractive_index }
+        Self {
+            refractive_index,
+            priority: 0,
+            absorption: Color::default(),
+        }
     }
 
-    fn reflectance(cosine: f32, refraction_index: f32) -> f32 {
-        // Use Schlick's approximation for reflectance
-        let r0 = (1.0 - refraction_index) / (1.0 + refraction_index);
-        let r0 = r0 * r0;
-        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    pub fn nested(refractive_index: f32, priority: i32, absorption: Color) -> Self {
+        Self {
+            refractive_index,
+            priority,
+            absorption,
+        }
     }
-}
 
-impl Material for Dialectric {
-    fn scatter(
+    pub fn interior(&self) -> Interior {
+        Interior {
+            refractive_index: self.refractive_index,
+            priority: self.priority,
+            absorption: self.absorption,
+        }
+    }
+
+    pub fn scatter_between(
         &self,
         ray_in: &Ray,
         record: &HitRecord,
+        ri: f32,
         attenuation: &mut Color,
         scattered: &mut Ray,
     ) -> bool {
+        // Reflect or refract across an interface where `ri` is the refractive index on the
+        // incident side over the refractive index on the transmitted side
         *attenuation = Color::new(1.0, 1.0, 1.0);
-        let ri = if record.front_face {
-            1.0 / self.refractive_index
-        } else {
-            self.refractive_index
-        };
 
         let unit_direction = ray_in.direction().unit_vector();
         let cos_theta = (-unit_direction).dot(&record.normal).min(1.0);
@@ -53,4 +68,29 @@ impl Material for Dialectric {
 
         true
     }
+
+    fn reflectance(cosine: f32, refraction_index: f32) -> f32 {
+        // Use Schlick's approximation for reflectance
+        let r0 = (1.0 - refraction_index) / (1.0 + refraction_index);
+        let r0 = r0 * r0;
+        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    }
+}
+
+impl Material for Dialectric {
+    fn scatter(
+        &self,
+        ray_in: &Ray,
+        record: &HitRecord,
+        attenuation: &mut Color,
+        scattered: &mut Ray,
+    ) -> bool {
+        let ri = if record.front_face {
+            1.0 / self.refractive_index
+        } else {
+            self.refractive_index
+        };
+
+        self.scatter_between(ray_in, record, ri, attenuation, scattered)
+    }
 }
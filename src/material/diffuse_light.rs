@@ -1,36 +1,188 @@
-use crate::{color::Color, texture::TextureType, vec3::Point3};
+use std::f32::consts::PI;
+
+use crate::{
+    color::Color,
+    hittable::HitRecord,
+    ray::Ray,
+    texture::TextureType,
+    utility::{degree_to_radians, smoothstep},
+    vec3::Point3,
+};
 
 use super::Material;
 
+// Luminous efficacy used to convert photometric units to radiometric ones, in lm/W
+const LUMENS_PER_WATT: f32 = 683.0;
+
 #[derive(Clone)]
 pub struct DiffuseLight {
     tex: TextureType,
+    // Scale applied to the texture to get the emitted radiance
+    scale: f32,
+    // Only emit from the front face, the side the normal points out of
+    one_sided: bool,
+    // Cosines of the angles from the normal where emission starts and finishes fading out
+    cos_spread_inner: f32,
+    cos_spread_outer: f32,
 }
 
 impl DiffuseLight {
     pub fn new(tex: TextureType) -> Self {
-        Self { tex }
+        Self {
+            tex,
+            scale: 1.0,
+            one_sided: false,
+            cos_spread_inner: -1.0,
+            cos_spread_outer: -1.0,
+        }
+    }
+
+    pub fn one_sided(tex: TextureType) -> Self {
+        Self {
+            one_sided: true,
+            ..Self::new(tex)
+        }
+    }
+
+    pub fn from_watts(tex: TextureType, watts: f32, area: f32, one_sided: bool) -> Self {
+        // Radiance of a Lambertian emitter of the given surface area radiating `watts` in total,
+        // tinted by the texture
+        let sides = if one_sided { 1.0 } else { 2.0 };
+        Self {
+            scale: watts / (PI * area * sides),
+            one_sided,
+            ..Self::new(tex)
+        }
+    }
+
+    pub fn from_nits(tex: TextureType, nits: f32, one_sided: bool) -> Self {
+        // Luminance in cd/m^2, such as a display panel's rated brightness, tinted by the texture
+        Self {
+            scale: nits / LUMENS_PER_WATT,
+            one_sided,
+            ..Self::new(tex)
+        }
+    }
+
+    pub fn with_spread(self, spread: f32) -> Self {
+        // Narrow the emission to a cone `spread` degrees wide around the normal, with a soft edge
+        // over its outermost tenth. A spread of 180 degrees or more is a plain diffuse emitter.
+        if spread >= 180.0 {
+            return Self {
+                cos_spread_inner: -1.0,
+                cos_spread_outer: -1.0,
+                ..self
+            };
+        }
+
+        let half_angle = degree_to_radians(spread.max(0.0) / 2.0);
+        Self {
+            cos_spread_inner: (0.9 * half_angle).cos(),
+            cos_spread_outer: half_angle.cos(),
+            ..self
+        }
+    }
+
+    pub fn is_one_sided(&self) -> bool {
+        self.one_sided
+    }
+
+    pub fn radiance_estimate(&self, p: &Point3) -> Color {
+        // Typical radiance leaving the surface near `p`, for weighing it against other lights
+        self.scale * self.tex.value(0.5, 0.5, p)
     }
 }
 
 impl Material for DiffuseLight {
-    fn emitted(&self, u: f32, v: f32, p: &Point3) -> Color {
-        self.tex.value(u, v, p)
+    fn emitted(&self, ray_in: &Ray, record: &HitRecord, u: f32, v: f32, p: &Point3) -> Color {
+        if self.one_sided && !record.front_face {
+            return Color::default();
+        }
+
+        // The recorded normal always faces back along the incoming ray
+        let falloff = if self.cos_spread_outer > -1.0 {
+            let cos_theta = record.normal.dot(&-ray_in.direction().unit_vector());
+            smoothstep(self.cos_spread_outer, self.cos_spread_inner, cos_theta)
+        } else {
+            1.0
+        };
+
+        (self.scale * falloff) * self.tex.value(u, v, p)
     }
 }
 
 impl From<Color> for DiffuseLight {
     fn from(value: Color) -> Self {
-        Self {
-            tex: TextureType::solid_color(&value),
-        }
+        Self::new(TextureType::solid_color(&value))
     }
 }
 
 impl From<&Color> for DiffuseLight {
     fn from(value: &Color) -> Self {
-        Self {
-            tex: TextureType::solid_color(value),
-        }
+        Self::new(TextureType::solid_color(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vec3::Vec3;
+
+    use super::*;
+
+    fn emitted_along(light: &DiffuseLight, direction: Vec3) -> f32 {
+        // Radiance seen by a ray travelling along `direction` onto a surface facing up the y axis
+        let ray = Ray::new(&(-direction), &direction, 0.0);
+        let mut record = HitRecord::default();
+        record.set_face_normal(&ray, &Vec3::new(0.0, 1.0, 0.0));
+        light
+            .emitted(&ray, &record, 0.5, 0.5, &Point3::default())
+            .x()
+    }
+
+    #[test]
+    fn one_sided_lights_are_dark_from_behind() {
+        let white = || TextureType::solid_color(&Color::new(1.0, 1.0, 1.0));
+        let down = Vec3::new(0.0, -1.0, 0.0);
+        let up = Vec3::new(0.0, 1.0, 0.0);
+
+        let one_sided = DiffuseLight::one_sided(white());
+        assert_eq!(emitted_along(&one_sided, down), 1.0);
+        assert_eq!(emitted_along(&one_sided, up), 0.0);
+
+        let two_sided = DiffuseLight::new(white());
+        assert_eq!(emitted_along(&two_sided, up), 1.0);
+    }
+
+    #[test]
+    fn converts_power_and_luminance_to_radiance() {
+        let white = || TextureType::solid_color(&Color::new(1.0, 1.0, 1.0));
+        let down = Vec3::new(0.0, -1.0, 0.0);
+
+        // 2 pi watts from both sides of a square metre is a radiance of one
+        let light = DiffuseLight::from_watts(white(), 2.0 * PI, 1.0, false);
+        assert!((emitted_along(&light, down) - 1.0).abs() < 1e-6);
+
+        // Putting it all out of one side doubles the radiance
+        let light = DiffuseLight::from_watts(white(), 2.0 * PI, 1.0, true);
+        assert!((emitted_along(&light, down) - 2.0).abs() < 1e-6);
+
+        let light = DiffuseLight::from_nits(white(), LUMENS_PER_WATT, true);
+        assert!((emitted_along(&light, down) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn spread_limits_emission_to_a_cone() {
+        let light = DiffuseLight::new(TextureType::solid_color(&Color::new(1.0, 1.0, 1.0)))
+            .with_spread(60.0);
+        let at_angle = |degrees: f32| {
+            let (sin_theta, cos_theta) = degree_to_radians(degrees).sin_cos();
+            emitted_along(&light, Vec3::new(sin_theta, -cos_theta, 0.0))
+        };
+
+        assert_eq!(at_angle(0.0), 1.0);
+        assert_eq!(at_angle(20.0), 1.0);
+        let edge = at_angle(28.5);
+        assert!(edge > 0.0 && edge < 1.0);
+        assert_eq!(at_angle(45.0), 0.0);
     }
 }
@@ -1,3 +1,5 @@
+use std::f32::consts::PI;
+
 use crate::{color::Color, hittable::HitRecord, ray::Ray, texture::TextureType, vec3::Vec3};
 
 use super::Material;
@@ -26,4 +28,8 @@ impl Material for Isotropic {
 
         true
     }
+
+    fn scattering_pdf(&self, _ray_in: &Ray, _record: &HitRecord, _scattered: &Ray) -> f32 {
+        1.0 / (4.0 * PI)
+    }
 }
@@ -1,3 +1,5 @@
+use std::f32::consts::PI;
+
 use crate::{color::Color, hittable::HitRecord, ray::Ray, texture::TextureType, vec3::Vec3};
 
 use super::Material;
@@ -44,4 +46,13 @@ impl Material for Lambertion {
         *attenuation = self.tex.value(record.u, record.v, &record.p);
         true
     }
+
+    fn scattering_pdf(&self, _ray_in: &Ray, record: &HitRecord, scattered: &Ray) -> f32 {
+        let cos_theta = record.normal.dot(&scattered.direction().unit_vector());
+        if cos_theta < 0.0 {
+            0.0
+        } else {
+            cos_theta / PI
+        }
+    }
 }
@@ -0,0 +1,63 @@
+use crate::vec3::Vec3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Onb {
+    axis: [Vec3; 3],
+}
+
+impl Onb {
+    pub fn new(n: &Vec3) -> Onb {
+        // Build an orthonormal basis whose w axis points along n
+        let w = n.unit_vector();
+        let a = if w.x().abs() > 0.9 {
+            Vec3::new(0.0, 1.0, 0.0)
+        } else {
+            Vec3::new(1.0, 0.0, 0.0)
+        };
+        let v = w.cross(&a).unit_vector();
+        let u = w.cross(&v);
+
+        Onb { axis: [u, v, w] }
+    }
+
+    pub fn with_reference(n: &Vec3, reference: &Vec3) -> Onb {
+        // Like `new`, but with the u axis pinned to the part of `reference` across n so the
+        // basis doesn't spin around n with the choice of helper axis
+        let w = n.unit_vector();
+        let across = *reference - reference.dot(&w) * w;
+        if across.near_zero() {
+            return Onb::new(n);
+        }
+
+        let u = across.unit_vector();
+        let v = u.cross(&w);
+
+        Onb { axis: [u, v, w] }
+    }
+
+    pub fn u(&self) -> &Vec3 {
+        &self.axis[0]
+    }
+
+    pub fn v(&self) -> &Vec3 {
+        &self.axis[1]
+    }
+
+    pub fn w(&self) -> &Vec3 {
+        &self.axis[2]
+    }
+
+    pub fn transform(&self, v: &Vec3) -> Vec3 {
+        // Transform from basis coordinates to local space
+        (v.x() * self.axis[0]) + (v.y() * self.axis[1]) + (v.z() * self.axis[2])
+    }
+
+    pub fn untransform(&self, v: &Vec3) -> Vec3 {
+        // Transform from local space to basis coordinates
+        Vec3::new(
+            v.dot(&self.axis[0]),
+            v.dot(&self.axis[1]),
+            v.dot(&self.axis[2]),
+        )
+    }
+}
@@ -1,19 +1,63 @@
+mod animated_boxes;
+mod area_lights;
+mod bokeh;
 mod bouncing_spheres;
 mod checkered_spheres;
 mod cornell_box;
 mod cornell_smoke;
+mod csg;
+mod delta_lights;
+mod distance_fields;
 mod earth;
+mod environment_map;
 mod final_scene;
+mod forest;
+mod glass_of_water;
+mod ies_lights;
+mod many_lights;
+mod motion_blur;
+mod orthographic;
+mod outdoor_sky;
+mod panorama;
 mod perlin_spheres;
+mod planar_shapes;
+mod primitives;
 mod quads;
+mod realistic_lens;
 mod simple_light;
+mod terrain;
+mod textured_background;
+mod transforms;
+mod turntable;
 
+pub use animated_boxes::animated_boxes;
+pub use area_lights::area_lights;
+pub use bokeh::bokeh;
 pub use bouncing_spheres::bouncing_spheres;
 pub use checkered_spheres::checkered_spheres;
 pub use cornell_box::cornell_box;
 pub use cornell_smoke::cornell_smoke;
+pub use csg::csg;
+pub use delta_lights::delta_lights;
+pub use distance_fields::distance_fields;
 pub use earth::earth;
+pub use environment_map::environment_map;
 pub use final_scene::final_scene;
+pub use forest::forest;
+pub use glass_of_water::glass_of_water;
+pub use ies_lights::ies_lights;
+pub use many_lights::many_lights;
+pub use motion_blur::motion_blur;
+pub use orthographic::orthographic;
+pub use outdoor_sky::outdoor_sky;
+pub use panorama::panorama;
 pub use perlin_spheres::perlin_spheres;
+pub use planar_shapes::planar_shapes;
+pub use primitives::primitives;
 pub use quads::quads;
+pub use realistic_lens::realistic_lens;
 pub use simple_light::simple_light;
+pub use terrain::terrain;
+pub use textured_background::textured_background;
+pub use transforms::transforms;
+pub use turntable::turntable;
@@ -0,0 +1,109 @@
+use crate::{
+    animation::{Interpolation, Track},
+    camera::Camera,
+    color::Color,
+    hittable::{animated::Pose, hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn animated_boxes() {
+    let mut world = HittableList::default();
+
+    let checker = TextureType::checker(0.5, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::quad(
+        Point3::new(-10.0, 0.0, -10.0),
+        Vec3::new(20.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 20.0),
+        MaterialType::lambertion(checker),
+    ));
+
+    // Unit cubes around their own centers, posed over the exposure from time 0 to 1
+    let cube = |color: Color| {
+        HittableObject::new_box(
+            Point3::new(-0.5, -0.5, -0.5),
+            Point3::new(0.5, 0.5, 0.5),
+            MaterialType::lambertion(color.into()),
+        )
+    };
+    let still = Vec3::default();
+    let unit = Vec3::new(1.0, 1.0, 1.0);
+
+    // Spinning in place
+    world.add(HittableObject::animated(
+        cube(Color::new(0.8, 0.3, 0.2)),
+        Track::new(
+            vec![
+                (0.0, Pose::new(Vec3::new(-2.5, 0.5, 0.0), still, unit)),
+                (
+                    1.0,
+                    Pose::new(Vec3::new(-2.5, 0.5, 0.0), Vec3::new(0.0, 60.0, 0.0), unit),
+                ),
+            ],
+            Interpolation::Linear,
+        ),
+    ));
+
+    // Easing into a jump while squashing and stretching
+    world.add(HittableObject::animated(
+        cube(Color::new(0.2, 0.5, 0.8)),
+        Track::new(
+            vec![
+                (
+                    0.0,
+                    Pose::new(Vec3::new(0.0, 0.35, 0.0), still, Vec3::new(1.2, 0.7, 1.2)),
+                ),
+                (
+                    1.0,
+                    Pose::new(
+                        Vec3::new(0.0, 1.5, 0.0),
+                        Vec3::new(0.0, 0.0, 20.0),
+                        Vec3::new(0.9, 1.3, 0.9),
+                    ),
+                ),
+            ],
+            Interpolation::Smooth,
+        ),
+    ));
+
+    // Jumping between two places halfway through the exposure
+    world.add(HittableObject::animated(
+        cube(Color::new(0.3, 0.7, 0.3)),
+        Track::new(
+            vec![
+                (0.0, Pose::new(Vec3::new(2.0, 0.5, -0.5), still, unit)),
+                (
+                    0.5,
+                    Pose::new(Vec3::new(3.0, 0.5, 0.5), Vec3::new(0.0, 45.0, 0.0), unit),
+                ),
+            ],
+            Interpolation::Step,
+        ),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 200;
+    cam.max_depth = 10;
+    cam.background = Color::new(0.7, 0.8, 1.0).into();
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -1.0),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+
+    cam.vfov = 35.0;
+    cam.look_from = Point3::new(0.0, 3.0, 9.0);
+    cam.look_at = Point3::new(0.0, 0.7, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
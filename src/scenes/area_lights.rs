@@ -0,0 +1,93 @@
+use crate::{
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    material::{diffuse_light::DiffuseLight, MaterialType},
+    texture::TextureType,
+    utility::{convert_to_linear, load_image},
+    vec3::{Point3, Vec3},
+};
+
+pub fn area_lights() {
+    let image = match load_image("earthmap.jpg") {
+        Ok(e) => convert_to_linear(e),
+        Err(e) => panic!("{:?}", e),
+    };
+
+    let mut world = HittableList::default();
+
+    let white = MaterialType::lambertion(Color::new(0.73, 0.73, 0.73).into());
+    let red = MaterialType::lambertion(Color::new(0.65, 0.05, 0.05).into());
+
+    // Floor and back wall of a dark room
+    world.add(HittableObject::quad(
+        Point3::new(-5.0, 0.0, -5.0),
+        Vec3::new(0.0, 0.0, 10.0),
+        Vec3::new(10.0, 0.0, 0.0),
+        white.clone(),
+    ));
+    world.add(HittableObject::quad(
+        Point3::new(-5.0, 0.0, -2.0),
+        Vec3::new(10.0, 0.0, 0.0),
+        Vec3::new(0.0, 6.0, 0.0),
+        white.clone(),
+    ));
+
+    // A television showing the earth at a typical display brightness
+    let screen = DiffuseLight::from_nits(TextureType::image(image), 600.0, true);
+    world.add(HittableObject::quad(
+        Point3::new(-1.6, 1.0, -1.9),
+        Vec3::new(3.2, 0.0, 0.0),
+        Vec3::new(0.0, 1.8, 0.0),
+        screen.into(),
+    ));
+
+    // A 60 W ceiling panel facing down, its back towards the ceiling stays dark
+    let panel = DiffuseLight::from_watts(Color::new(1.0, 0.9, 0.8).into(), 60.0, 1.0, true);
+    world.add(HittableObject::quad(
+        Point3::new(2.0, 5.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        panel.into(),
+    ));
+
+    // A narrow beam from a panel near the floor onto the red sphere
+    let beam = DiffuseLight::from_watts(Color::new(0.6, 0.7, 1.0).into(), 20.0, 0.25, true)
+        .with_spread(40.0);
+    world.add(HittableObject::quad(
+        Point3::new(-4.0, 0.1, 2.0),
+        Vec3::new(0.0, 0.5, 0.0),
+        Vec3::new(0.0, 0.0, 0.5),
+        beam.into(),
+    ));
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(2.5, 0.8, 0.5),
+        0.8,
+        red,
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(-2.5, 0.6, 1.0),
+        0.6,
+        white,
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 200;
+    cam.max_depth = 20;
+    cam.background = Color::new(0.0, 0.0, 0.0).into();
+
+    cam.vfov = 50.0;
+    cam.look_from = Point3::new(0.0, 2.5, 7.0);
+    cam.look_at = Point3::new(0.0, 1.5, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
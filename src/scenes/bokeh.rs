@@ -0,0 +1,68 @@
+use crate::{
+    camera::{aperture::Aperture, Camera},
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::{point::Falloff, LightType},
+    material::MaterialType,
+    utility::{random_float, random_float_clamp},
+    vec3::{Point3, Vec3},
+};
+
+pub fn bokeh(aperture: Aperture) {
+    let mut world = HittableList::default();
+    let mut lights = vec![];
+
+    // A sharp subject in front of a wall of small lights far out of focus
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, 0.0, 0.0),
+        0.5,
+        MaterialType::lambertion(Color::new(0.8, 0.3, 0.2).into()),
+    ));
+
+    for _ in 0..60 {
+        let center = Point3::new(
+            random_float_clamp(-6.0, 6.0),
+            random_float_clamp(-3.5, 3.5),
+            random_float_clamp(-22.0, -18.0),
+        );
+        let warmth = random_float();
+        let glow = HittableObject::stationary_sphere(
+            center,
+            0.05,
+            MaterialType::diffuse_light(
+                Color::new(40.0, 30.0 + 10.0 * warmth, 15.0 + 25.0 * warmth).into(),
+            ),
+        );
+
+        lights.extend(LightType::area(&glow));
+        world.add(glow);
+    }
+
+    lights.push(LightType::point(
+        Point3::new(2.0, 2.0, 3.0),
+        Color::new(6.0, 6.0, 6.0),
+        Falloff::InverseSquare,
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 200;
+    cam.max_depth = 10;
+    cam.background = Color::new(0.01, 0.01, 0.02).into();
+    cam.lights = lights;
+
+    cam.vfov = 20.0;
+    cam.look_from = Point3::new(0.0, 0.0, 5.0);
+    cam.look_at = Point3::new(0.0, 0.0, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 3.0;
+    cam.focus_dist = 5.0;
+    cam.aperture = aperture;
+
+    cam.render(&world);
+}
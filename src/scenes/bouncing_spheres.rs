@@ -1,4 +1,5 @@
 use crate::{
+    background::BackgroundType,
     camera::Camera,
     color::Color,
     hittable::{hittable_list::HittableList, HittableObject},
@@ -67,9 +68,10 @@ pub fn bouncing_spheres() {
         TextureType::checker(0.32, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
 
     let ground_material = MaterialType::lambertion(checker);
-    world.add(HittableObject::stationary_sphere(
-        Point3::new(0.0, -1000.0, 0.0),
-        1000.0,
+    world.add(HittableObject::plane(
+        Point3::new(0.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        1.0,
         ground_material,
     ));
 
@@ -146,7 +148,8 @@ pub fn bouncing_spheres() {
     camera.image_width = 400;
     camera.samples_per_pixel = 100;
     camera.max_depth = 50;
-    camera.background = Color::new(0.70, 0.80, 1.00);
+    camera.background =
+        BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
 
     camera.vfov = 20.0;
     camera.look_from = Point3::new(13.0, 2.0, 3.0);
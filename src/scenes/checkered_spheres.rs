@@ -36,7 +36,7 @@ pub fn checkered_spheres() {
     cam.image_width = 400;
     cam.samples_per_pixel = 100;
     cam.max_depth = 50;
-    cam.background = Color::new(0.70, 0.80, 1.00);
+    cam.background = Color::new(0.70, 0.80, 1.00).into();
 
     cam.vfov = 20.0;
     cam.look_from = Point3::new(13.0, 2.0, 13.0);
@@ -2,6 +2,7 @@ use crate::{
     camera::Camera,
     color::Color,
     hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
     material::MaterialType,
     vec3::{Point3, Vec3},
 };
@@ -12,7 +13,7 @@ pub fn cornell_box() {
     let red = MaterialType::lambertion(Color::new(0.65, 0.05, 0.05).into());
     let white = MaterialType::lambertion(Color::new(0.73, 0.73, 0.73).into());
     let green = MaterialType::lambertion(Color::new(0.12, 0.45, 0.15).into());
-    let light = MaterialType::diffuse_light(Color::new(15.0, 15.0, 15.0).into());
+    let light = MaterialType::one_sided_light(Color::new(15.0, 15.0, 15.0).into());
 
     world.add(HittableObject::quad(
         Point3::new(555.0, 0.0, 0.0),
@@ -28,12 +29,14 @@ pub fn cornell_box() {
         red,
     ));
 
-    world.add(HittableObject::quad(
+    let ceiling_light = HittableObject::quad(
         Point3::new(343.0, 554.0, 332.0),
         Vec3::new(-130.0, 0.0, 0.0),
         Vec3::new(0.0, 0.0, -105.0),
         light,
-    ));
+    );
+    let lights = LightType::area(&ceiling_light);
+    world.add(ceiling_light);
 
     world.add(HittableObject::quad(
         Point3::new(0.0, 0.0, 0.0),
@@ -86,7 +89,8 @@ pub fn cornell_box() {
     cam.image_width = 600;
     cam.samples_per_pixel = 200;
     cam.max_depth = 5;
-    cam.background = Color::new(0.0, 0.0, 0.0);
+    cam.background = Color::new(0.0, 0.0, 0.0).into();
+    cam.lights.extend(lights);
 
     cam.vfov = 40.0;
     cam.look_from = Point3::new(278.0, 278.0, -800.0);
@@ -96,7 +96,7 @@ pub fn cornell_smoke() {
     cam.image_width = 600;
     cam.samples_per_pixel = 200;
     cam.max_depth = 50;
-    cam.background = Color::new(0.0, 0.0, 0.0);
+    cam.background = Color::new(0.0, 0.0, 0.0).into();
 
     cam.vfov = 40.0;
     cam.look_from = Point3::new(278.0, 278.0, -800.0);
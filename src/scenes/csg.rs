@@ -0,0 +1,123 @@
+use crate::{
+    background::BackgroundType,
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn csg() {
+    let mut world = HittableList::default();
+
+    let ground = TextureType::checker(
+        0.5,
+        &Color::new(0.3, 0.3, 0.35),
+        &Color::new(0.85, 0.85, 0.85),
+    );
+    world.add(HittableObject::plane(
+        Point3::new(0.0, -1.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        1.0,
+        MaterialType::lambertion(ground),
+    ));
+
+    let solid = |color: Color| MaterialType::lambertion(TextureType::solid_color(&color));
+
+    // The textbook tree: a box rounded off by a sphere, drilled through along all three axes.
+    // Each cut surface keeps the colour of whichever solid it came from.
+    let rounded_box = HittableObject::intersection(
+        HittableObject::new_box(
+            Point3::new(-1.0, -1.0, -1.0),
+            Point3::new(1.0, 1.0, 1.0),
+            solid(Color::new(0.8, 0.15, 0.1)),
+        ),
+        HittableObject::stationary_sphere(
+            Point3::default(),
+            1.35,
+            solid(Color::new(0.1, 0.25, 0.8)),
+        ),
+    );
+    let drill = |axis: Vec3| {
+        HittableObject::cylinder(
+            -2.0 * axis,
+            2.0 * axis,
+            0.5,
+            true,
+            solid(Color::new(0.1, 0.7, 0.2)),
+        )
+    };
+    let drills = HittableObject::union(
+        HittableObject::union(
+            drill(Vec3::new(1.0, 0.0, 0.0)),
+            drill(Vec3::new(0.0, 1.0, 0.0)),
+        ),
+        drill(Vec3::new(0.0, 0.0, 1.0)),
+    );
+    world.add(HittableObject::rotate_y(
+        HittableObject::difference(rounded_box, drills),
+        30.0,
+    ));
+
+    // A glass lens where two spheres overlap, which only refracts properly if the ray knows when
+    // it goes in and out of the combined solid
+    world.add(HittableObject::intersection(
+        HittableObject::stationary_sphere(
+            Point3::new(2.2, 0.0, 1.2),
+            1.0,
+            MaterialType::dialectric(1.5),
+        ),
+        HittableObject::stationary_sphere(
+            Point3::new(3.4, 0.0, 1.2),
+            1.0,
+            MaterialType::dialectric(1.5),
+        ),
+    ));
+
+    // A metal shell with the top sliced off, showing the hollow inside
+    world.add(HittableObject::difference(
+        HittableObject::difference(
+            HittableObject::stationary_sphere(
+                Point3::new(-2.8, 0.0, 0.5),
+                1.0,
+                MaterialType::metal(Color::new(0.8, 0.6, 0.3), 0.2),
+            ),
+            HittableObject::stationary_sphere(
+                Point3::new(-2.8, 0.0, 0.5),
+                0.9,
+                solid(Color::new(0.9, 0.9, 0.9)),
+            ),
+        ),
+        HittableObject::new_box(
+            Point3::new(-4.0, 0.4, -0.7),
+            Point3::new(-1.6, 1.2, 1.7),
+            MaterialType::None,
+        ),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -1.5),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+
+    cam.vfov = 40.0;
+    cam.look_from = Point3::new(1.0, 4.0, 9.0);
+    cam.look_at = Point3::new(0.0, 0.0, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
@@ -0,0 +1,81 @@
+use crate::{
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::{point::Falloff, LightType},
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn delta_lights() {
+    let mut world = HittableList::default();
+
+    let checker =
+        TextureType::checker(0.32, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, -1000.0, 0.0),
+        1000.0,
+        MaterialType::lambertion(checker),
+    ));
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(-4.0, 1.0, 0.0),
+        1.0,
+        MaterialType::lambertion(Color::new(0.4, 0.2, 0.1).into()),
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, 1.0, 0.0),
+        1.0,
+        MaterialType::dialectric(1.5),
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(4.0, 1.0, 0.0),
+        1.0,
+        MaterialType::metal(Color::new(0.7, 0.6, 0.5), 0.0),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 50;
+    cam.background = Color::new(0.02, 0.02, 0.05).into();
+
+    // A low, soft edged sun, a warm bulb by the diffuse sphere, a faint fill light that doesn't
+    // fade with distance and a spot on the metal sphere.
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -0.5, -0.3),
+        Color::new(1.0, 0.9, 0.7),
+        2.0,
+    ));
+    cam.lights.push(LightType::point(
+        Point3::new(-4.0, 3.0, 2.0),
+        Color::new(8.0, 6.0, 3.0),
+        Falloff::InverseSquare,
+    ));
+    cam.lights.push(LightType::point(
+        Point3::new(10.0, 8.0, 10.0),
+        Color::new(0.05, 0.05, 0.08),
+        Falloff::None,
+    ));
+    cam.lights.push(LightType::spot(
+        Point3::new(4.0, 6.0, 3.0),
+        Point3::new(4.0, 0.0, 0.0),
+        Color::new(40.0, 40.0, 50.0),
+        10.0,
+        20.0,
+    ));
+
+    cam.vfov = 20.0;
+    cam.look_from = Point3::new(13.0, 2.0, 3.0);
+    cam.look_at = Point3::new(0.0, 0.0, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
@@ -0,0 +1,125 @@
+use crate::{
+    aabb::Aabb,
+    background::BackgroundType,
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, sdf::field::DistanceField, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn distance_fields() {
+    let mut world = HittableList::default();
+
+    let ground = TextureType::checker(
+        0.5,
+        &Color::new(0.3, 0.3, 0.35),
+        &Color::new(0.85, 0.85, 0.85),
+    );
+    world.add(HittableObject::plane(
+        Point3::new(0.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        1.0,
+        MaterialType::lambertion(ground),
+    ));
+
+    let solid = |color: Color| MaterialType::lambertion(TextureType::solid_color(&color));
+    let bounds = |a: Point3, b: Point3| Aabb::from_points(&a, &b);
+
+    // The power eight Mandelbulb, standing on the floor
+    world.add(HittableObject::sdf(
+        DistanceField::mandelbulb(8.0, 8).translate(Vec3::new(0.0, 1.2, 0.0)),
+        bounds(
+            Point3::new(-1.25, 0.0, -1.25),
+            Point3::new(1.25, 2.45, 1.25),
+        ),
+        MaterialType::metal(Color::new(0.8, 0.6, 0.3), 0.3),
+    ));
+
+    // A rounded column twisted about its axis
+    world.add(HittableObject::sdf(
+        DistanceField::cuboid(Vec3::new(0.4, 1.0, 0.4), 0.05)
+            .twist(1.2)
+            .translate(Vec3::new(-2.8, 1.0, 0.0)),
+        bounds(Point3::new(-3.4, 0.0, -0.6), Point3::new(-2.2, 2.0, 0.6)),
+        solid(Color::new(0.2, 0.4, 0.8)),
+    ));
+
+    // A sphere and a capsule melting into each other
+    let blob = DistanceField::sphere(0.5)
+        .translate(Vec3::new(2.5, 0.5, 0.0))
+        .union(
+            DistanceField::capsule(
+                Point3::new(2.7, 0.3, 0.3),
+                Point3::new(3.2, 1.2, -0.2),
+                0.25,
+            ),
+            0.3,
+        );
+    world.add(HittableObject::sdf(
+        blob,
+        bounds(Point3::new(1.9, 0.0, -0.7), Point3::new(3.7, 1.7, 0.8)),
+        solid(Color::new(0.8, 0.3, 0.4)),
+    ));
+
+    // A ring with a bite taken out of it, smoothed round the edges of the bite
+    world.add(HittableObject::sdf(
+        DistanceField::torus(0.45, 0.15)
+            .difference(
+                DistanceField::sphere(0.25).translate(Vec3::new(0.0, 0.15, 0.45)),
+                0.05,
+            )
+            .translate(Vec3::new(-0.2, 0.15, 2.6)),
+        bounds(Point3::new(-0.85, 0.0, 1.95), Point3::new(0.45, 0.32, 3.25)),
+        solid(Color::new(0.3, 0.7, 0.4)),
+    ));
+
+    // A carpet of beads, repeated forever and cut off by the bounds between two rows
+    world.add(HittableObject::sdf(
+        DistanceField::sphere(0.12)
+            .translate(Vec3::new(0.0, 0.12, 0.0))
+            .repeat(Vec3::new(0.4, 0.0, 0.4)),
+        bounds(Point3::new(0.6, 0.0, 1.4), Point3::new(3.4, 0.25, 3.0)),
+        MaterialType::metal(Color::new(0.8, 0.8, 0.85), 0.05),
+    ));
+
+    // A ball carved out of a gyroid, from a distance function written on the spot
+    let gyroid = DistanceField::custom(|p| {
+        let q = 8.0 * *p;
+        let g = q.x().sin() * q.y().cos() + q.y().sin() * q.z().cos() + q.z().sin() * q.x().cos();
+        0.5 * g.abs() / 8.0 - 0.02
+    });
+    world.add(HittableObject::sdf(
+        gyroid
+            .intersection(DistanceField::sphere(0.6), 0.0)
+            .translate(Vec3::new(-1.6, 0.6, 2.0)),
+        bounds(Point3::new(-2.2, 0.0, 1.4), Point3::new(-1.0, 1.2, 2.6)),
+        solid(Color::new(0.9, 0.8, 0.3)),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -1.5),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+
+    cam.vfov = 40.0;
+    cam.look_from = Point3::new(0.0, 3.0, 9.0);
+    cam.look_at = Point3::new(0.0, 0.9, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
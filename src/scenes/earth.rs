@@ -24,7 +24,7 @@ pub fn earth() {
     cam.image_width = 1920;
     cam.samples_per_pixel = 500;
     cam.max_depth = 50;
-    cam.background = Color::new(0.70, 0.80, 1.00);
+    cam.background = Color::new(0.70, 0.80, 1.00).into();
 
     cam.vfov = 20.0;
     cam.look_from = Point3::new(0.0, 0.0, 12.0);
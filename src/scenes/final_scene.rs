@@ -133,7 +133,7 @@ pub fn final_scene(image_width: u32, samples_per_pixel: u32, max_depth: u32) {
     cam.image_width = image_width;
     cam.samples_per_pixel = samples_per_pixel;
     cam.max_depth = max_depth;
-    cam.background = Color::new(0.0, 0.0, 0.0);
+    cam.background = Color::new(0.0, 0.0, 0.0).into();
 
     cam.vfov = 40.0;
     cam.look_from = Point3::new(478.0, 278.0, -600.0);
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use crate::{
+    background::BackgroundType,
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    mat4::Mat4,
+    material::MaterialType,
+    utility::{random_float, random_float_clamp},
+    vec3::{Point3, Vec3},
+};
+
+pub fn forest() {
+    let mut world = HittableList::default();
+
+    world.add(HittableObject::quad(
+        Point3::new(-60.0, 0.0, -60.0),
+        Vec3::new(120.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 120.0),
+        MaterialType::lambertion(Color::new(0.3, 0.4, 0.2).into()),
+    ));
+
+    // One trunk and one canopy, shared by every tree in the forest
+    let trunk = Arc::new(HittableObject::new_box(
+        Point3::new(-0.1, 0.0, -0.1),
+        Point3::new(0.1, 1.0, 0.1),
+        MaterialType::lambertion(Color::new(0.35, 0.25, 0.15).into()),
+    ));
+    let mut canopy = HittableList::default();
+    for (y, radius) in [(1.2, 0.6), (1.7, 0.45), (2.1, 0.3)] {
+        canopy.add(HittableObject::stationary_sphere(
+            Point3::new(0.0, y, 0.0),
+            radius,
+            MaterialType::None,
+        ));
+    }
+    let canopy = Arc::new(HittableObject::BvhNode(canopy.into()));
+
+    // Autumn colors given to the canopies in place of their own material
+    let leaves: Vec<Arc<MaterialType>> = [
+        Color::new(0.2, 0.45, 0.15),
+        Color::new(0.15, 0.35, 0.1),
+        Color::new(0.7, 0.45, 0.1),
+        Color::new(0.6, 0.2, 0.1),
+    ]
+    .into_iter()
+    .map(|color| Arc::new(MaterialType::lambertion(color.into())))
+    .collect();
+
+    let mut trees = HittableList::default();
+    for a in -50..50 {
+        for b in -50..50 {
+            let position = Vec3::new(
+                a as f32 + random_float_clamp(0.1, 0.9),
+                0.0,
+                b as f32 + random_float_clamp(0.1, 0.9),
+            );
+            let size = random_float_clamp(0.7, 1.3);
+            let placement = Mat4::translation(position)
+                * Mat4::rotation(Vec3::new(0.0, 1.0, 0.0), random_float_clamp(0.0, 360.0))
+                * Mat4::scaling(Vec3::new(size, size * random_float_clamp(0.8, 1.2), size));
+
+            let leaf =
+                leaves[(random_float() * leaves.len() as f32) as usize % leaves.len()].clone();
+            trees.add(HittableObject::instance(&trunk, placement, None));
+            trees.add(HittableObject::instance(&canopy, placement, Some(leaf)));
+        }
+    }
+    world.add(HittableObject::BvhNode(trees.into()));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 50;
+    cam.max_depth = 10;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -1.0, -0.5),
+        Color::new(2.5, 2.3, 2.0),
+        0.5,
+    ));
+
+    // Looking across ten thousand trees from above the edge of the forest
+    cam.vfov = 40.0;
+    cam.look_from = Point3::new(0.0, 12.0, 60.0);
+    cam.look_at = Point3::new(0.0, 0.0, 20.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
@@ -0,0 +1,75 @@
+use crate::{
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn glass_of_water() {
+    let mut world = HittableList::default();
+
+    let checker = TextureType::checker(0.5, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, -1000.0, 0.0),
+        1000.0,
+        MaterialType::lambertion(checker),
+    ));
+
+    // The tank is solid glass, the water and the air above it carve the cavity out of the glass
+    // by having a higher priority, and the ice cube outranks both of them.
+    let glass = MaterialType::nested_dialectric(1.5, 1, Color::default());
+    let water = MaterialType::nested_dialectric(1.33, 2, Color::new(0.3, 0.08, 0.02));
+    let air = MaterialType::nested_dialectric(1.0, 2, Color::default());
+    let ice = MaterialType::nested_dialectric(1.31, 3, Color::default());
+
+    world.add(HittableObject::new_box(
+        Point3::new(-1.0, 0.0, -1.0),
+        Point3::new(1.0, 2.5, 1.0),
+        glass,
+    ));
+    world.add(HittableObject::new_box(
+        Point3::new(-0.9, 0.1, -0.9),
+        Point3::new(0.9, 1.6, 0.9),
+        water,
+    ));
+    world.add(HittableObject::new_box(
+        Point3::new(-0.9, 1.6, -0.9),
+        Point3::new(0.9, 2.6, 0.9),
+        air,
+    ));
+
+    let cube = HittableObject::new_box(
+        Point3::new(-0.3, -0.3, -0.3),
+        Point3::new(0.3, 0.3, 0.3),
+        ice,
+    );
+    let cube = HittableObject::rotate_y(cube, 30.0);
+    world.add(HittableObject::translate(cube, Vec3::new(0.1, 1.5, 0.0)));
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, 0.7, -3.0),
+        0.7,
+        MaterialType::lambertion(Color::new(0.8, 0.2, 0.1).into()),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 200;
+    cam.max_depth = 50;
+    cam.background = Color::new(0.70, 0.80, 1.00).into();
+
+    cam.vfov = 30.0;
+    cam.look_from = Point3::new(2.0, 3.0, 7.0);
+    cam.look_at = Point3::new(0.0, 1.2, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
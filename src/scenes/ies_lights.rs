@@ -0,0 +1,80 @@
+use crate::{
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::{ies::IesProfile, LightType},
+    material::MaterialType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn ies_lights() {
+    let downlight = match IesProfile::load("downlight.ies") {
+        Ok(profile) => profile,
+        Err(e) => panic!("{:?}", e),
+    };
+
+    let mut world = HittableList::default();
+
+    let white = MaterialType::lambertion(Color::new(0.73, 0.73, 0.73).into());
+
+    // Floor and a back wall close to the luminaires so their beams scallop down it
+    world.add(HittableObject::quad(
+        Point3::new(-6.0, 0.0, -6.0),
+        Vec3::new(0.0, 0.0, 12.0),
+        Vec3::new(12.0, 0.0, 0.0),
+        white.clone(),
+    ));
+    world.add(HittableObject::quad(
+        Point3::new(-6.0, 0.0, -1.0),
+        Vec3::new(12.0, 0.0, 0.0),
+        Vec3::new(0.0, 4.0, 0.0),
+        white.clone(),
+    ));
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, 0.5, 1.0),
+        0.5,
+        MaterialType::metal(Color::new(0.8, 0.8, 0.8), 0.1),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 20;
+    cam.background = Color::new(0.0, 0.0, 0.0).into();
+
+    // Candela converted to radiometric units at 683 lm/W, with the exposure of a dim interior
+    let exposure = 20.0;
+    let peak = exposure * downlight.peak_candela() / 683.0;
+    for x in [-3.0, 0.0, 3.0] {
+        cam.lights.push(LightType::ies_point(
+            Point3::new(x, 3.5, -0.5),
+            Point3::new(x, 0.0, -0.5),
+            Vec3::new(0.0, 0.0, 1.0),
+            Color::new(1.0, 0.85, 0.7) * peak,
+            downlight.clone(),
+        ));
+    }
+    cam.lights.push(LightType::ies_spot(
+        Point3::new(2.0, 3.5, 3.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        Color::new(0.7, 0.8, 1.0) * peak,
+        25.0,
+        35.0,
+        downlight,
+    ));
+
+    cam.vfov = 50.0;
+    cam.look_from = Point3::new(0.0, 2.0, 8.0);
+    cam.look_at = Point3::new(0.0, 1.5, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
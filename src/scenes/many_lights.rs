@@ -0,0 +1,83 @@
+use crate::{
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    utility::{random_float, random_float_clamp},
+    vec3::{Point3, Vec3},
+};
+
+pub fn many_lights() {
+    let mut world = HittableList::default();
+    let mut lights = vec![];
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, -1000.0, 0.0),
+        1000.0,
+        MaterialType::lambertion(Color::new(0.5, 0.5, 0.5).into()),
+    ));
+
+    // A field of small glowing lanterns of every colour, most of them far from any given point
+    for a in -12..12 {
+        for b in -12..12 {
+            let center = Point3::new(
+                a as f32 + 0.8 * random_float(),
+                random_float_clamp(0.3, 1.5),
+                b as f32 + 0.8 * random_float(),
+            );
+            let lantern = HittableObject::stationary_sphere(
+                center,
+                0.08,
+                MaterialType::diffuse_light((8.0 * Color::random_clamp(0.2, 1.0)).into()),
+            );
+
+            lights.extend(LightType::area(&lantern));
+            world.add(lantern);
+        }
+    }
+
+    // A row of panels facing down onto the field
+    for i in -3..=3 {
+        let panel = HittableObject::quad(
+            Point3::new(2.0 * i as f32 - 0.4, 3.0, -0.4),
+            Vec3::new(0.0, 0.0, 0.8),
+            Vec3::new(0.8, 0.0, 0.0),
+            MaterialType::one_sided_light(Color::new(4.0, 3.6, 3.0).into()),
+        );
+
+        lights.extend(LightType::area(&panel));
+        world.add(panel);
+    }
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(-2.0, 1.0, 0.0),
+        1.0,
+        MaterialType::lambertion(Color::new(0.8, 0.3, 0.2).into()),
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(2.0, 1.0, 0.0),
+        1.0,
+        MaterialType::metal(Color::new(0.8, 0.8, 0.9), 0.1),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 64;
+    cam.max_depth = 20;
+    cam.background = Color::new(0.0, 0.0, 0.0).into();
+    cam.lights = lights;
+
+    cam.vfov = 40.0;
+    cam.look_from = Point3::new(0.0, 4.0, 12.0);
+    cam.look_at = Point3::new(0.0, 0.5, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
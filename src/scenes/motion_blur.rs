@@ -0,0 +1,60 @@
+use crate::{
+    camera::{shutter::Shutter, Camera},
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn motion_blur(shutter: Shutter) {
+    let mut world = HittableList::default();
+
+    let checker = TextureType::checker(
+        0.5,
+        &Color::new(0.2, 0.2, 0.25),
+        &Color::new(0.85, 0.85, 0.85),
+    );
+    world.add(HittableObject::quad(
+        Point3::new(-8.0, -3.0, -2.0),
+        Vec3::new(16.0, 0.0, 0.0),
+        Vec3::new(0.0, 8.0, 0.0),
+        MaterialType::lambertion(checker),
+    ));
+
+    // A column of balls crossing the view together from left to right during the exposure
+    for i in 0..5 {
+        let y = 2.0 - i as f32;
+        let hue = i as f32 / 4.0;
+        world.add(HittableObject::moving_sphere(
+            Point3::new(-3.0, y, 0.0),
+            Point3::new(3.0, y, 0.0),
+            0.4,
+            MaterialType::lambertion(Color::new(0.9 - 0.7 * hue, 0.4, 0.2 + 0.7 * hue).into()),
+        ));
+    }
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 200;
+    cam.max_depth = 10;
+    cam.background = Color::new(0.7, 0.8, 1.0).into();
+    cam.lights.push(LightType::directional(
+        Vec3::new(-0.5, -1.0, -2.0),
+        Color::new(2.0, 2.0, 2.0),
+        0.5,
+    ));
+
+    cam.vfov = 40.0;
+    cam.look_from = Point3::new(0.0, 0.0, 8.0);
+    cam.look_at = Point3::new(0.0, 0.0, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+    cam.shutter = shutter;
+
+    cam.render(&world);
+}
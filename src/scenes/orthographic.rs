@@ -0,0 +1,67 @@
+use crate::{
+    background::BackgroundType,
+    camera::{projection::Projection, Camera},
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn orthographic() {
+    let mut world = HittableList::default();
+
+    let checker =
+        TextureType::checker(0.5, &Color::new(0.3, 0.3, 0.35), &Color::new(0.8, 0.8, 0.8));
+    world.add(HittableObject::quad(
+        Point3::new(-4.0, 0.0, -4.0),
+        Vec3::new(8.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 8.0),
+        MaterialType::lambertion(checker),
+    ));
+
+    // A stack of blocks, the same size near and far when seen without perspective
+    let block = MaterialType::lambertion(Color::new(0.8, 0.5, 0.2).into());
+    for (corner, size) in [
+        (Point3::new(-2.0, 0.0, -2.0), 2.0),
+        (Point3::new(-1.5, 2.0, -1.5), 1.0),
+        (Point3::new(1.0, 0.0, 1.0), 1.0),
+        (Point3::new(1.0, 0.0, -2.5), 1.0),
+    ] {
+        world.add(HittableObject::new_box(
+            corner,
+            corner + Vec3::new(size, size, size),
+            block.clone(),
+        ));
+    }
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(-2.0, 0.75, 2.0),
+        0.75,
+        MaterialType::metal(Color::new(0.8, 0.8, 0.9), 0.05),
+    ));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.6, 0.7, 0.9));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -0.5),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+
+    // An isometric view, looking down the diagonal of a cube
+    cam.projection = Projection::Orthographic { view_height: 7.0 };
+    cam.look_from = Point3::new(10.0, 10.0, 10.0);
+    cam.look_at = Point3::new(0.0, 0.5, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
@@ -0,0 +1,65 @@
+use crate::{
+    background::{sky::Sky, BackgroundType},
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn outdoor_sky() {
+    let mut world = HittableList::default();
+
+    let checker =
+        TextureType::checker(0.32, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, -1000.0, 0.0),
+        1000.0,
+        MaterialType::lambertion(checker),
+    ));
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(-4.0, 1.0, 0.0),
+        1.0,
+        MaterialType::lambertion(Color::new(0.4, 0.2, 0.1).into()),
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, 1.0, 0.0),
+        1.0,
+        MaterialType::dialectric(1.5),
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(4.0, 1.0, 0.0),
+        1.0,
+        MaterialType::metal(Color::new(0.7, 0.6, 0.5), 0.0),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    // Late afternoon sun behind the camera's left shoulder
+    let sky = Sky::new(
+        Vec3::new(0.6, 0.35, 0.5),
+        3.0,
+        Color::new(0.3, 0.3, 0.3),
+        0.02,
+    );
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 50;
+    cam.lights.push(sky.sun());
+    cam.background = BackgroundType::Sky(sky);
+
+    cam.vfov = 20.0;
+    cam.look_from = Point3::new(13.0, 2.0, 3.0);
+    cam.look_at = Point3::new(0.0, 0.0, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
@@ -0,0 +1,72 @@
+use std::f32::consts::PI;
+
+use crate::{
+    background::BackgroundType,
+    camera::{projection::Projection, stereo::Stereo, Camera},
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn panorama(projection: Projection, stereo: Option<Stereo>) {
+    let mut world = HittableList::default();
+
+    let checker = TextureType::checker(1.0, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, -1000.0, 0.0),
+        1000.0,
+        MaterialType::lambertion(checker),
+    ));
+
+    // A ring of spheres all the way around the camera, red straight ahead
+    let count = 8;
+    for i in 0..count {
+        let angle = 2.0 * PI * i as f32 / count as f32;
+        let hue = i as f32 / count as f32;
+        let albedo = Color::new(
+            0.5 + 0.5 * (2.0 * PI * hue).cos(),
+            0.5 + 0.5 * (2.0 * PI * (hue - 1.0 / 3.0)).cos(),
+            0.5 + 0.5 * (2.0 * PI * (hue - 2.0 / 3.0)).cos(),
+        );
+        world.add(HittableObject::stationary_sphere(
+            Point3::new(4.0 * angle.sin(), 1.0, -4.0 * angle.cos()),
+            1.0,
+            MaterialType::lambertion(albedo.into()),
+        ));
+    }
+
+    world.add(HittableObject::quad(
+        Point3::new(-1.0, 5.0, -1.0),
+        Vec3::new(2.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 2.0),
+        MaterialType::diffuse_light(Color::new(4.0, 4.0, 4.0).into()),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.projection = projection;
+    cam.stereo = stereo;
+    cam.image_width = 600;
+    cam.samples_per_pixel = 50;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -1.5, -0.5),
+        Color::new(1.5, 1.4, 1.2),
+        0.5,
+    ));
+
+    cam.look_from = Point3::new(0.0, 1.0, 0.0);
+    cam.look_at = Point3::new(0.0, 1.0, -1.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    // Converge on the ring of spheres
+    cam.focus_dist = 4.0;
+
+    cam.render(&world);
+}
@@ -27,7 +27,7 @@ pub fn perlin_spheres() {
     camera.image_width = 400;
     camera.samples_per_pixel = 100;
     camera.max_depth = 50;
-    camera.background = Color::new(0.70, 0.80, 1.00);
+    camera.background = Color::new(0.70, 0.80, 1.00).into();
 
     camera.vfov = 20.0;
     camera.look_from = Point3::new(13.0, 2.0, 3.0);
@@ -0,0 +1,81 @@
+use crate::{
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn planar_shapes() {
+    let mut world = HittableList::default();
+
+    // Materials
+    let red = MaterialType::lambertion(TextureType::solid_color(&Color::new(1.0, 0.2, 0.2)));
+    let green = MaterialType::lambertion(TextureType::solid_color(&Color::new(0.2, 1.0, 0.2)));
+    let checker = MaterialType::lambertion(TextureType::checker(
+        0.25,
+        &Color::new(0.2, 0.2, 1.0),
+        &Color::new(0.9, 0.9, 0.9),
+    ));
+    let orange = MaterialType::lambertion(TextureType::solid_color(&Color::new(1.0, 0.5, 0.0)));
+    let warm_light =
+        MaterialType::diffuse_light(TextureType::solid_color(&Color::new(15.0, 13.0, 10.0)));
+
+    // One of each shape, all spanned by the same kind of u and v
+    world.add(HittableObject::quad(
+        Point3::new(-5.0, -1.5, 0.0),
+        Vec3::new(2.5, 0.0, 0.0),
+        Vec3::new(0.5, 3.0, 0.0),
+        red,
+    ));
+    world.add(HittableObject::triangle(
+        Point3::new(-2.0, -1.5, 0.0),
+        Vec3::new(3.0, 0.0, 0.0),
+        Vec3::new(0.5, 3.0, 0.0),
+        green,
+    ));
+    world.add(HittableObject::ellipse(
+        Point3::new(2.5, 0.0, 0.0),
+        Vec3::new(1.5, 0.0, 0.0),
+        Vec3::new(0.3, 1.5, 0.0),
+        checker,
+    ));
+    world.add(HittableObject::annulus(
+        Point3::new(6.0, 0.0, 0.0),
+        Vec3::new(1.5, 0.0, 0.0),
+        Vec3::new(0.0, 1.5, 0.0),
+        0.5,
+        orange,
+    ));
+
+    // A ring of light overhead, sampled like any other area light
+    let light = HittableObject::annulus(
+        Point3::new(0.5, 5.0, 3.0),
+        Vec3::new(3.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, -3.0),
+        0.7,
+        warm_light,
+    );
+    let lights = LightType::area(&light);
+    world.add(light);
+
+    let mut camera = Camera::default();
+
+    camera.aspect_ratio = 16.0 / 9.0;
+    camera.image_width = 400;
+    camera.samples_per_pixel = 100;
+    camera.max_depth = 50;
+    camera.background = Color::new(0.02, 0.02, 0.03).into();
+    camera.lights.extend(lights);
+
+    camera.vfov = 60.0;
+    camera.look_from = Point3::new(0.5, 0.0, 10.0);
+    camera.look_at = Point3::new(0.5, 0.0, 0.0);
+    camera.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    camera.defocus_angle = 0.0;
+
+    camera.render(&world);
+}
@@ -0,0 +1,98 @@
+use crate::{
+    background::BackgroundType,
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    utility::{convert_to_linear, load_image},
+    vec3::{Point3, Vec3},
+};
+
+pub fn primitives() {
+    let mut world = HittableList::default();
+
+    let ground = TextureType::checker(
+        0.5,
+        &Color::new(0.3, 0.3, 0.35),
+        &Color::new(0.85, 0.85, 0.85),
+    );
+    world.add(HittableObject::quad(
+        Point3::new(-10.0, 0.0, -10.0),
+        Vec3::new(20.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 20.0),
+        MaterialType::lambertion(ground),
+    ));
+
+    // The earth wrapped around each shape shows how its surface is parameterised
+    let image = match load_image("earthmap.jpg") {
+        Ok(e) => convert_to_linear(e),
+        Err(e) => panic!("{:?}", e),
+    };
+    let earth = || MaterialType::lambertion(TextureType::image(image.clone()));
+
+    // A disk turned mostly towards the camera
+    world.add(HittableObject::disk(
+        Point3::new(-3.2, 1.0, -1.0),
+        Vec3::new(0.3, 0.2, 1.0),
+        0.9,
+        earth(),
+    ));
+
+    // A capped cylinder standing up, and an open pipe lying on its side
+    world.add(HittableObject::cylinder(
+        Point3::new(-1.3, 0.0, 0.0),
+        Point3::new(-1.3, 1.6, 0.0),
+        0.6,
+        true,
+        earth(),
+    ));
+    world.add(HittableObject::cylinder(
+        Point3::new(-1.0, 0.35, 1.8),
+        Point3::new(0.6, 0.35, 2.4),
+        0.35,
+        false,
+        MaterialType::metal(Color::new(0.8, 0.6, 0.3), 0.1),
+    ));
+
+    world.add(HittableObject::cone(
+        Point3::new(0.9, 0.0, 0.0),
+        Point3::new(0.9, 1.8, 0.0),
+        0.7,
+        earth(),
+    ));
+
+    // A ring tilted towards the camera
+    world.add(HittableObject::torus(
+        Point3::new(3.0, 1.0, 0.0),
+        Vec3::new(0.0, 1.0, 1.2),
+        0.8,
+        0.3,
+        earth(),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -1.5),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+
+    cam.vfov = 40.0;
+    cam.look_from = Point3::new(0.0, 3.0, 9.0);
+    cam.look_at = Point3::new(0.0, 0.8, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
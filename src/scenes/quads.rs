@@ -56,7 +56,7 @@ pub fn quads() {
     camera.image_width = 1800;
     camera.samples_per_pixel = 100;
     camera.max_depth = 50;
-    camera.background = Color::new(0.70, 0.80, 1.00);
+    camera.background = Color::new(0.70, 0.80, 1.00).into();
 
     camera.vfov = 80.0;
     camera.look_from = Point3::new(0.0, 0.0, 9.0);
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::{
+    background::BackgroundType,
+    camera::{lens::LensSystem, projection::Projection, Camera},
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn realistic_lens(lens: LensSystem) {
+    let mut world = HittableList::default();
+
+    // Scene units are metres to match the lens, which is measured in millimetres
+    let checker = TextureType::checker(0.1, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::quad(
+        Point3::new(-5.0, 0.0, -12.0),
+        Vec3::new(10.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 14.0),
+        MaterialType::lambertion(checker),
+    ));
+
+    // A row of balls receding from the camera, sharp only around the focus distance
+    for i in 0..8 {
+        let z = 1.0 - 1.2 * i as f32;
+        let hue = i as f32 / 8.0;
+        world.add(HittableObject::stationary_sphere(
+            Point3::new(0.35 - 0.1 * i as f32, 0.15, z),
+            0.15,
+            MaterialType::lambertion(Color::new(0.9 - 0.7 * hue, 0.3, 0.2 + 0.7 * hue).into()),
+        ));
+    }
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 3.0 / 2.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 200;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -1.0),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+
+    // A 50mm lens on a full frame film, focused on the third ball
+    cam.projection = Projection::Lens {
+        lens: Arc::new(lens),
+        film_diagonal: 43.27,
+    };
+    cam.look_from = Point3::new(0.0, 0.4, 3.0);
+    cam.look_at = Point3::new(0.0, 0.15, -1.4);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+    cam.focus_dist = 4.4;
+
+    cam.render(&world);
+}
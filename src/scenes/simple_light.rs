@@ -43,7 +43,7 @@ pub fn simple_light() {
     cam.image_width = 1800;
     cam.samples_per_pixel = 100;
     cam.max_depth = 50;
-    cam.background = Color::new(0.0, 0.0, 0.0);
+    cam.background = Color::new(0.0, 0.0, 0.0).into();
 
     cam.vfov = 20.0;
     cam.look_from = Point3::new(26.0, 3.0, 6.0);
@@ -0,0 +1,64 @@
+use crate::{
+    background::BackgroundType,
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    utility::{convert_to_linear, load_image},
+    vec3::{Point3, Vec3},
+};
+
+pub fn terrain() {
+    let mut world = HittableList::default();
+
+    let heights = match load_image("heightmap.png") {
+        Ok(e) => e,
+        Err(e) => panic!("{:?}", e),
+    };
+    // Colour map made from the same heights, so each pixel lands on the point it describes
+    let colors = match load_image("heightmap_color.png") {
+        Ok(e) => convert_to_linear(e),
+        Err(e) => panic!("{:?}", e),
+    };
+
+    world.add(HittableObject::heightfield(
+        &heights,
+        Point3::new(-10.0, 0.0, -10.0),
+        Vec3::new(20.0, 3.0, 20.0),
+        MaterialType::lambertion(TextureType::image(colors)),
+    ));
+
+    // Calm water filling the low ground
+    world.add(HittableObject::quad(
+        Point3::new(-10.0, 0.45, -10.0),
+        Vec3::new(20.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 20.0),
+        MaterialType::metal(Color::new(0.3, 0.45, 0.6), 0.05),
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -1.0, -0.5),
+        Color::new(2.5, 2.3, 2.0),
+        0.5,
+    ));
+
+    cam.vfov = 45.0;
+    cam.look_from = Point3::new(0.0, 10.0, 17.0);
+    cam.look_at = Point3::new(0.0, 0.0, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
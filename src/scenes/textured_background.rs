@@ -0,0 +1,49 @@
+use crate::{
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn textured_background() {
+    let mut world = HittableList::default();
+
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(-2.2, 0.0, 0.0),
+        1.0,
+        MaterialType::lambertion(Color::new(0.8, 0.8, 0.8).into()),
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(0.0, 0.0, 0.0),
+        1.0,
+        MaterialType::dialectric(1.5),
+    ));
+    world.add(HittableObject::stationary_sphere(
+        Point3::new(2.2, 0.0, 0.0),
+        1.0,
+        MaterialType::metal(Color::new(0.8, 0.8, 0.8), 0.05),
+    ));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 50;
+
+    // The checker is evaluated on the unit direction of each escaped ray, wrapping the scene in
+    // a tiled dome.
+    cam.background =
+        TextureType::checker(0.2, &Color::new(0.9, 0.5, 0.1), &Color::new(0.1, 0.2, 0.6)).into();
+
+    cam.vfov = 40.0;
+    cam.look_from = Point3::new(0.0, 1.0, 7.0);
+    cam.look_at = Point3::new(0.0, 0.0, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
@@ -0,0 +1,85 @@
+use crate::{
+    background::BackgroundType,
+    camera::Camera,
+    color::Color,
+    hittable::{hittable_list::HittableList, HittableObject},
+    light::LightType,
+    mat4::Mat4,
+    material::MaterialType,
+    texture::TextureType,
+    vec3::{Point3, Vec3},
+};
+
+pub fn transforms() {
+    let mut world = HittableList::default();
+
+    let checker = TextureType::checker(0.5, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::quad(
+        Point3::new(-10.0, 0.0, -10.0),
+        Vec3::new(20.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 20.0),
+        MaterialType::lambertion(checker),
+    ));
+
+    // A cube balanced on one corner, turned about the x axis and then the z axis
+    let cube = HittableObject::new_box(
+        Point3::new(-0.5, -0.5, -0.5),
+        Point3::new(0.5, 0.5, 0.5),
+        MaterialType::lambertion(Color::new(0.8, 0.3, 0.2).into()),
+    );
+    let cube = HittableObject::rotate(cube, Vec3::new(1.0, 0.0, 0.0), 45.0);
+    let cube = HittableObject::rotate(cube, Vec3::new(0.0, 0.0, 1.0), 35.26);
+    world.add(HittableObject::translate(cube, Vec3::new(-2.2, 0.87, 0.0)));
+
+    // A mirrored ellipsoid, squashed and tilted about a diagonal axis
+    let ball = HittableObject::stationary_sphere(
+        Point3::default(),
+        1.0,
+        MaterialType::metal(Color::new(0.8, 0.8, 0.9), 0.0),
+    );
+    let ball = HittableObject::scale(ball, Vec3::new(1.0, 0.45, 0.7));
+    let ball = HittableObject::rotate(ball, Vec3::new(1.0, 0.0, 1.0), -30.0);
+    world.add(HittableObject::translate(ball, Vec3::new(0.0, 0.8, 0.0)));
+
+    // A box leaning over like a stack of sliding cards, sheared along x as it rises
+    let shear = Mat4 {
+        m: [
+            [1.0, 0.6, 0.0, 2.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ],
+    };
+    world.add(HittableObject::transform(
+        HittableObject::new_box(
+            Point3::new(-0.4, 0.0, -0.4),
+            Point3::new(0.4, 1.6, 0.4),
+            MaterialType::lambertion(Color::new(0.2, 0.5, 0.8).into()),
+        ),
+        shear,
+    ));
+
+    let world = HittableList::new(HittableObject::BvhNode(world.into()));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 400;
+    cam.samples_per_pixel = 100;
+    cam.max_depth = 20;
+    cam.background = BackgroundType::gradient(Color::new(1.0, 1.0, 1.0), Color::new(0.5, 0.7, 1.0));
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -1.0),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+
+    cam.vfov = 35.0;
+    cam.look_from = Point3::new(0.0, 3.0, 8.0);
+    cam.look_at = Point3::new(0.0, 0.7, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+
+    cam.defocus_angle = 0.0;
+
+    cam.render(&world);
+}
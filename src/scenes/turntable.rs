@@ -0,0 +1,103 @@
+use std::f32::consts::PI;
+
+use crate::{
+    animation::{Interpolation, Track},
+    camera::{sequence::Sequence, shutter::Shutter, Camera},
+    color::Color,
+    hittable::{animated::Pose, hittable_list::HittableList, HittableObject},
+    light::LightType,
+    material::MaterialType,
+    texture::TextureType,
+    utility::random_float_clamp,
+    vec3::{Point3, Vec3},
+};
+
+pub fn turntable() {
+    let mut world = HittableList::default();
+
+    // A field of still balls, built into a hierarchy once for the whole sequence
+    let checker = TextureType::checker(0.5, &Color::new(0.2, 0.3, 0.1), &Color::new(0.9, 0.9, 0.9));
+    world.add(HittableObject::quad(
+        Point3::new(-10.0, 0.0, -10.0),
+        Vec3::new(20.0, 0.0, 0.0),
+        Vec3::new(0.0, 0.0, 20.0),
+        MaterialType::lambertion(checker),
+    ));
+    for a in -4..4 {
+        for b in -4..4 {
+            if (-1..1).contains(&a) && (-1..1).contains(&b) {
+                continue;
+            }
+            let center = Point3::new(a as f32 + 0.5, 0.2, b as f32 + 0.5);
+            let albedo = Color::random_clamp(0.2, 0.9);
+            world.add(HittableObject::stationary_sphere(
+                center,
+                random_float_clamp(0.15, 0.3),
+                MaterialType::lambertion(albedo.into()),
+            ));
+        }
+    }
+
+    // A cube bouncing in the middle, twice a second
+    let rest = Vec3::new(0.0, 0.5, 0.0);
+    let unit = Vec3::new(1.0, 1.0, 1.0);
+    world.add(HittableObject::animated(
+        HittableObject::new_box(
+            Point3::new(-0.5, -0.5, -0.5),
+            Point3::new(0.5, 0.5, 0.5),
+            MaterialType::lambertion(Color::new(0.8, 0.3, 0.2).into()),
+        ),
+        Track::new(
+            (0..=4)
+                .map(|i| {
+                    let height = if i % 2 == 0 { 0.0 } else { 1.5 };
+                    let turn = Vec3::new(0.0, 45.0 * i as f32, 0.0);
+                    (
+                        0.25 * i as f32,
+                        Pose::new(rest + Vec3::new(0.0, height, 0.0), turn, unit),
+                    )
+                })
+                .collect(),
+            Interpolation::Smooth,
+        ),
+    ));
+
+    let mut cam = Camera::default();
+
+    cam.aspect_ratio = 16.0 / 9.0;
+    cam.image_width = 240;
+    cam.samples_per_pixel = 40;
+    cam.max_depth = 10;
+    cam.background = Color::new(0.7, 0.8, 1.0).into();
+    cam.lights.push(LightType::directional(
+        Vec3::new(-1.0, -2.0, -1.0),
+        Color::new(2.0, 1.9, 1.7),
+        0.5,
+    ));
+    cam.look_at = Point3::new(0.0, 0.5, 0.0);
+    cam.vup = Vec3::new(0.0, 1.0, 0.0);
+    cam.defocus_angle = 0.0;
+
+    // Half a frame of exposure at 12 frames per second
+    cam.shutter = Shutter::default();
+    cam.shutter.close = 0.5 / 12.0;
+
+    // Circling once around the field over the second, zooming in towards the end
+    let mut sequence = Sequence::new(1..=12, 12.0);
+    sequence.look_from = Some(Track::new(
+        (0..=16)
+            .map(|i| {
+                let angle = 2.0 * PI * i as f32 / 16.0;
+                let orbit = Point3::new(9.0 * angle.sin(), 4.0, 9.0 * angle.cos());
+                (i as f32 / 16.0, orbit)
+            })
+            .collect(),
+        Interpolation::Linear,
+    ));
+    sequence.vfov = Some(Track::new(
+        vec![(0.5, 35.0), (1.0, 20.0)],
+        Interpolation::Smooth,
+    ));
+
+    cam.render_sequence(&world, &sequence);
+}
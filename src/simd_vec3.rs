@@ -27,7 +27,7 @@ const NEAR_ZERO: f32 = 1e-8;
 
 impl Vec3 {
     #[inline(always)]
-    pub fn new(x: f32, y: f32, z: f32) -> Vec3 {
+    pub const fn new(x: f32, y: f32, z: f32) -> Vec3 {
         Vec3 {
             e: Simd::from_array([x, y, z]),
         }
@@ -8,6 +8,16 @@ pub fn degree_to_radians(degrees: f32) -> f32 {
     degrees * PI / 180.0
 }
 
+pub fn turn_fraction(x: f32, y: f32) -> f32 {
+    // Angle of (x, y) counterclockwise from the x axis, as a fraction of a whole turn in [0, 1)
+    let phi = y.atan2(x);
+    if phi < 0.0 {
+        phi / (2.0 * PI) + 1.0
+    } else {
+        phi / (2.0 * PI)
+    }
+}
+
 #[inline]
 pub fn random_float() -> f32 {
     // Returns a random real [0.0..1.0)
@@ -26,13 +36,24 @@ pub fn random_uint_clamp(min: usize, max: usize) -> usize {
     random_range(min..=max)
 }
 
-pub fn load_image(filename: &str) -> Result<DynamicImage, ImageError> {
-    let image_dir = match std::env::var("RTW_IMAGES") {
+#[inline]
+pub fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    // Hermite interpolation from 0 at edge0 to 1 at edge1
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn image_dir() -> Option<String> {
+    // Directory holding the images and other assets, when set in the environment
+    match std::env::var("RTW_IMAGES") {
         Ok(image_dir) => Some(image_dir),
         Err(VarError::NotPresent) => None,
         Err(e) => panic!("{:?}", e),
-    };
-    if let Some(image_dir) = image_dir {
+    }
+}
+
+pub fn load_image(filename: &str) -> Result<DynamicImage, ImageError> {
+    if let Some(image_dir) = image_dir() {
         return image::open(image_dir + filename);
     }
 
@@ -52,6 +73,18 @@ pub fn load_image(filename: &str) -> Result<DynamicImage, ImageError> {
     image::open(String::from("../../../../../../images/") + filename)
 }
 
+pub fn read_asset(filename: &str) -> std::io::Result<String> {
+    // Text assets such as photometric files and lens prescriptions live next to the images and
+    // are searched for the same way
+    if let Some(image_dir) = image_dir() {
+        return std::fs::read_to_string(image_dir + filename);
+    }
+
+    (0..7)
+        .find_map(|depth| std::fs::read_to_string("../".repeat(depth) + "images/" + filename).ok())
+        .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::NotFound))
+}
+
 pub fn convert_to_linear(image: DynamicImage) -> DynamicImage {
     let pixels: Vec<u8> = image
         .to_rgb32f()
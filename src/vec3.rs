@@ -18,7 +18,7 @@ impl Default for Vec3 {
 }
 
 impl Vec3 {
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
         Self { e: [x, y, z] }
     }
 